@@ -0,0 +1,87 @@
+use crate::{Error, Printer, Transport};
+use std::future::Future;
+
+impl Printer {
+    /// Creates the printer with the given VID/PID, like [`Printer::new`], without blocking the async runtime.
+    ///
+    /// USB enumeration and opening the device run on tokio's blocking thread pool.
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, Printer};
+    /// # async fn run() -> Result<(), Error> {
+    /// let Some(printer) = Printer::connect_async(0x04b8, 0x0202).await? else {
+    ///     return Err(escpos_rw::Error::PrinterError(
+    ///         "No printer found !".to_string(),
+    ///     ));
+    /// };
+    /// # Ok(())}
+    /// ```
+    pub async fn connect_async(vendor_id: u16, product_id: u16) -> Result<Option<Printer>, Error> {
+        tokio::task::spawn_blocking(move || Printer::new(vendor_id, product_id))
+            .await
+            .map_err(|e| Error::PrinterError(format!("Connection task failed: {}", e)))?
+    }
+
+    /// Sends bytes to the printer, like [`Printer::write_raw`], without blocking the async runtime.
+    ///
+    /// The bytes are copied, and the transfers run on tokio's blocking thread pool: the returned future doesn't
+    /// borrow the printer, and can be spawned. Only USB printers are supported.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # async fn run() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::connect_async(0x04b8, 0x0202).await? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.write_raw_async(b"Hello\n").await?;
+    /// # Ok(())}
+    /// ```
+    pub fn write_raw_async<A: AsRef<[u8]>>(
+        &self,
+        bytes: A,
+    ) -> impl Future<Output = Result<(), Error>> + Send + 'static {
+        let transfer = self.transfer(self.write_timeout());
+        let bytes = bytes.as_ref().to_vec();
+        async move {
+            let transfer = transfer?;
+            tokio::task::spawn_blocking(move || transfer.write(&bytes))
+                .await
+                .map_err(|e| Error::PrinterError(format!("Write task failed: {}", e)))?
+        }
+    }
+
+    /// Reads at most `len` bytes from the printer, like [`Printer::read`], without blocking the async runtime.
+    ///
+    /// The transfer runs on tokio's blocking thread pool, and the returned future doesn't borrow the printer. Only USB
+    /// printers are supported.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # async fn run() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::connect_async(0x04b8, 0x0202).await? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Paper sensor status
+    /// printer.write_raw_async([0x10, 0x04, 0x04]).await?;
+    /// let status = printer.read_async(1).await?;
+    /// # Ok(())}
+    /// ```
+    pub fn read_async(
+        &self,
+        len: usize,
+    ) -> impl Future<Output = Result<Vec<u8>, Error>> + Send + 'static {
+        let transfer = self.transfer(self.read_timeout());
+        async move {
+            let transfer = transfer?;
+            tokio::task::spawn_blocking(move || {
+                let mut buffer = vec![0; len];
+                let read = transfer.read(&mut buffer)?;
+                buffer.truncate(read);
+                Ok(buffer)
+            })
+            .await
+            .map_err(|e| Error::PrinterError(format!("Read task failed: {}", e)))?
+        }
+    }
+}
@@ -0,0 +1,478 @@
+use crate::{Error, Justification, Printer};
+
+/// Barcode symbologies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Barcode {
+    /// UPC-A, 11 or 12 digits
+    UpcA,
+    /// UPC-E, 6 to 8 or 11 to 12 digits
+    UpcE,
+    /// EAN-13 (JAN-13), 12 or 13 digits
+    Ean13,
+    /// EAN-8 (JAN-8), 7 or 8 digits
+    Ean8,
+    /// CODE39: digits, uppercase letters and ` $%*+-./`
+    Code39,
+    /// Interleaved 2 of 5, an even number of digits
+    Itf,
+    /// CODABAR (NW-7): digits and `$+-./:`, starting and ending with one of `ABCD`
+    Codabar,
+    /// CODE93, any ASCII character
+    Code93,
+    /// CODE128, any ASCII character. The data may start with a code set selection (`{A`, `{B` or `{C`),
+    /// code set B is used otherwise.
+    Code128,
+}
+
+impl Barcode {
+    /// Symbology code of the length-prefixed `GS k` form
+    fn code(self) -> u8 {
+        match self {
+            Barcode::UpcA => 65,
+            Barcode::UpcE => 66,
+            Barcode::Ean13 => 67,
+            Barcode::Ean8 => 68,
+            Barcode::Code39 => 69,
+            Barcode::Itf => 70,
+            Barcode::Codabar => 71,
+            Barcode::Code93 => 72,
+            Barcode::Code128 => 73,
+        }
+    }
+}
+
+/// Position of the human readable interpretation (HRI) of barcodes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HriPosition {
+    /// Not printed
+    None,
+    /// Above the barcode
+    Above,
+    /// Below the barcode
+    #[default]
+    Below,
+    /// Above and below the barcode
+    Both,
+}
+
+/// Checks the data can be encoded with the symbology, and returns the bytes to send.
+/// CODE128 data without a code set selection gets code set B selected.
+fn barcode_data(symbology: Barcode, data: &str) -> Result<Vec<u8>, Error> {
+    let bytes = data.as_bytes();
+    let digits = bytes.iter().all(|b| b.is_ascii_digit());
+    let valid = match symbology {
+        Barcode::UpcA => digits && (11..=12).contains(&bytes.len()),
+        Barcode::UpcE => digits && matches!(bytes.len(), 6..=8 | 11..=12),
+        Barcode::Ean13 => digits && (12..=13).contains(&bytes.len()),
+        Barcode::Ean8 => digits && (7..=8).contains(&bytes.len()),
+        Barcode::Code39 => {
+            !bytes.is_empty()
+                && bytes.iter().all(|b| {
+                    b.is_ascii_digit() || b.is_ascii_uppercase() || b" $%*+-./".contains(b)
+                })
+        }
+        Barcode::Itf => digits && !bytes.is_empty() && bytes.len().is_multiple_of(2),
+        Barcode::Codabar => {
+            let start_stop = |b: &u8| b"ABCDabcd".contains(b);
+            bytes.len() >= 2
+                && start_stop(&bytes[0])
+                && start_stop(&bytes[bytes.len() - 1])
+                && bytes[1..bytes.len() - 1]
+                    .iter()
+                    .all(|b| b.is_ascii_digit() || b"$+-./:".contains(b))
+        }
+        Barcode::Code93 | Barcode::Code128 => !bytes.is_empty() && data.is_ascii(),
+    };
+    if !valid {
+        return Err(Error::PrinterError(format!(
+            "Invalid data for a {:?} barcode: {}",
+            symbology, data
+        )));
+    }
+    let bytes = match symbology {
+        Barcode::Code128 if !matches!(bytes, [b'{', b'A' | b'B' | b'C', ..]) => {
+            [b"{B", bytes].concat()
+        }
+        _ => bytes.to_vec(),
+    };
+    if bytes.len() > u8::MAX as usize {
+        return Err(Error::PrinterError(format!(
+            "Barcode data is too long: {} bytes",
+            bytes.len()
+        )));
+    }
+    Ok(bytes)
+}
+
+/// Height, in dots, of product barcodes
+const PRODUCT_BARCODE_HEIGHT: u8 = 80;
+
+/// GS1 check digit of UPC and EAN codes, computed on the digits preceding it
+fn check_digit(digits: &[u8]) -> u8 {
+    // From the right, digits are weighted 3, 1, 3, 1...
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| (d - b'0') as u32 * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    b'0' + ((10 - sum % 10) % 10) as u8
+}
+
+/// Validates a product code: 11 digits get their UPC-A check digit appended, 12 digits UPC-A and 13 digits
+/// EAN-13 codes must have a correct check digit
+fn product_code(code: &str) -> Result<(Barcode, Vec<u8>), Error> {
+    if !code.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::PrinterError(format!(
+            "Product code must only contain digits: {}",
+            code
+        )));
+    }
+    let digits = code.as_bytes();
+    let (symbology, check) = match digits.len() {
+        11 => return Ok((Barcode::UpcA, [digits, &[check_digit(digits)]].concat())),
+        12 => (Barcode::UpcA, digits[11]),
+        13 => (Barcode::Ean13, digits[12]),
+        n => {
+            return Err(Error::PrinterError(format!(
+                "Product code must be 11 or 12 digits (UPC-A) or 13 digits (EAN-13), got {}",
+                n
+            )))
+        }
+    };
+    let expected = check_digit(&digits[..digits.len() - 1]);
+    if check != expected {
+        return Err(Error::PrinterError(format!(
+            "Invalid check digit in {}: expected {}",
+            code, expected as char
+        )));
+    }
+    Ok((symbology, digits.to_vec()))
+}
+
+/// Quiet zone on each side of a barcode, in modules, when the symbology doesn't define its own
+const QUIET_ZONE: u32 = 10;
+
+/// Number of CODE128 symbols needed for the data, start code included
+fn code128_symbols(data: &[u8]) -> u32 {
+    // Code set B is selected when the data doesn't start with a code set selection
+    let mut symbols = if data.first() == Some(&b'{') { 0 } else { 1 };
+    let mut bytes = data.iter();
+    while let Some(byte) = bytes.next() {
+        // Special characters and code set selections are a "{" followed by a character.
+        // In code set C, each byte is a 0..=99 value encoding a pair of digits.
+        if *byte == b'{' {
+            bytes.next();
+        }
+        symbols += 1;
+    }
+    symbols
+}
+
+/// Barcode commands (`GS k`, length-prefixed form), for data already checked
+fn barcode_command(
+    symbology: Barcode,
+    data: &[u8],
+    height: u8,
+    module_width: u8,
+    hri: HriPosition,
+) -> Vec<u8> {
+    let hri = match hri {
+        HriPosition::None => 0,
+        HriPosition::Above => 1,
+        HriPosition::Below => 2,
+        HriPosition::Both => 3,
+    };
+    // Height, module width, and HRI characters position
+    let mut bytes = vec![0x1D, 0x68, height, 0x1D, 0x77, module_width];
+    bytes.extend_from_slice(&[0x1D, 0x48, hri]);
+    bytes.extend_from_slice(&[0x1D, 0x6B, symbology.code(), data.len() as u8]);
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// Bytes of the commands printing a barcode, checking the data as [`Printer::print_barcode`] does
+/// ```rust
+/// use escpos_rw::{barcode_bytes, Barcode, HriPosition};
+/// let bytes = barcode_bytes(Barcode::Ean13, "4006381333931", 80, 2, HriPosition::Below).unwrap();
+/// assert!(bytes.ends_with(b"4006381333931"));
+/// assert!(barcode_bytes(Barcode::Ean13, "ABC", 80, 2, HriPosition::Below).is_err());
+/// ```
+pub fn barcode_bytes(
+    sym: Barcode,
+    data: &str,
+    height: u8,
+    width: u8,
+    hri: HriPosition,
+) -> Result<Vec<u8>, Error> {
+    if height == 0 {
+        return Err(Error::PrinterError(
+            "Barcode height must not be zero".to_string(),
+        ));
+    }
+    if !(2..=6).contains(&width) {
+        return Err(Error::PrinterError(format!(
+            "Barcode module width must be between 2 and 6, got {}",
+            width
+        )));
+    }
+    let data = barcode_data(sym, data)?;
+    Ok(barcode_command(sym, &data, height, width, hri))
+}
+
+impl Printer {
+    /// Width, in dots, of a barcode printed with the given module width (`GS w`), quiet zones included.
+    ///
+    /// For CODE39, ITF and CODABAR, the wide elements are 2.5 times the module width, as on Epson printers.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::Barcode;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let width = printer.barcode_width_dots(Barcode::Ean13, "4006381333931", 3);
+    /// assert_eq!(width, 339);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn barcode_width_dots(&self, symbology: Barcode, data: &str, module_width: u8) -> u16 {
+        let narrow = module_width as u32;
+        let wide = (5 * narrow).div_ceil(2);
+        let quiet_zones = 2 * QUIET_ZONE * narrow;
+        let data = data.as_bytes();
+        let width = match symbology {
+            // Modules, plus their own quiet zones
+            Barcode::UpcA => (95 + 9 + 9) * narrow,
+            Barcode::UpcE => (51 + 9 + 7) * narrow,
+            Barcode::Ean13 => (95 + 11 + 7) * narrow,
+            Barcode::Ean8 => (67 + 7 + 7) * narrow,
+            Barcode::Code39 => {
+                // Characters are 6 narrow and 3 wide elements, separated by a narrow gap.
+                // Start and stop characters are added if missing.
+                let characters = data.iter().filter(|b| **b != b'*').count() as u32 + 2;
+                characters * (6 * narrow + 3 * wide) + (characters - 1) * narrow + quiet_zones
+            }
+            Barcode::Itf => {
+                // Pairs of digits are 6 narrow and 4 wide elements
+                let pairs = data.len() as u32 / 2;
+                let start = 4 * narrow;
+                let stop = wide + 2 * narrow;
+                pairs * (6 * narrow + 4 * wide) + start + stop + quiet_zones
+            }
+            Barcode::Codabar => {
+                let characters: u32 = data
+                    .iter()
+                    .map(|b| match b {
+                        b'0'..=b'9' | b'-' | b'$' => 5 * narrow + 2 * wide,
+                        _ => 4 * narrow + 3 * wide,
+                    })
+                    .sum();
+                let gaps = (data.len() as u32).saturating_sub(1) * narrow;
+                characters + gaps + quiet_zones
+            }
+            Barcode::Code93 => {
+                // 9 modules per character, with start, stop, two check characters and the termination bar
+                ((data.len() as u32 + 4) * 9 + 1) * narrow + quiet_zones
+            }
+            Barcode::Code128 => {
+                // 11 modules per symbol, with the check symbol, and 13 for the stop pattern
+                ((code128_symbols(data) + 1) * 11 + 13) * narrow + quiet_zones
+            }
+        };
+        width.min(u16::MAX as u32) as u16
+    }
+
+    /// Prints a barcode, `height` dots high, with modules `width` dots wide (2 to 6).
+    ///
+    /// The data is checked against the symbology: digits for UPC and EAN, with or without the check digit,
+    /// the characters listed in [`Barcode`] otherwise. The length-prefixed form of `GS k` is always used, which
+    /// handles all symbologies: CODE128 data only needs a code set selection when not using code set B.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::{Barcode, HriPosition};
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.print_barcode(Barcode::Code128, "INV-2024-0042", 80, 2, HriPosition::Below)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_barcode(
+        &self,
+        sym: Barcode,
+        data: &str,
+        height: u8,
+        width: u8,
+        hri: HriPosition,
+    ) -> Result<(), Error> {
+        self.write_raw(barcode_bytes(sym, data, height, width, hri)?)
+    }
+
+    /// Prints a shelf label: the product name, its UPC-A or EAN-13 barcode and its price, centered.
+    ///
+    /// 11 digits codes are UPC-A codes without their check digit, which is computed. Otherwise, the check digit is
+    /// verified. The barcode is printed as wide as the profile width allows.
+    ///
+    /// The justification and character size are restored when it returns.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.print_product_barcode("4006381333931", "Ballpoint pen", "1.99")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_product_barcode(&self, upc: &str, name: &str, price: &str) -> Result<(), Error> {
+        let (symbology, data) = product_code(upc)?;
+        let code = String::from_utf8_lossy(&data);
+        // Widest modules fitting the paper, but at least 2 dots to be scannable
+        let module_width = (2..=4)
+            .rev()
+            .find(|w| self.barcode_width_dots(symbology, &code, *w) <= self.profile.width())
+            .unwrap_or(2);
+
+        let previous = self.justification.get();
+        self.set_justification(Justification::Center)?;
+        let printed = (|| {
+            let mut line = self.code_page.get().encode(name, b'?');
+            line.push(b'\n');
+            self.write_raw(line)?;
+            self.write_raw(barcode_command(
+                symbology,
+                &data,
+                PRODUCT_BARCODE_HEIGHT,
+                module_width,
+                HriPosition::Below,
+            ))?;
+            // Price in double width and height, then back to the previous size
+            let mut line = vec![0x1D, 0x21, 0x11];
+            line.extend(self.code_page.get().encode(price, b'?'));
+            line.extend_from_slice(&[b'\n', 0x1D, 0x21, self.char_size.get()]);
+            self.write_raw(line)
+        })();
+        let restored = self.set_justification(previous);
+        printed.and(restored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VecTransport;
+
+    #[test]
+    fn check_digits() {
+        // UPC-A 036000291452
+        assert_eq!(check_digit(b"03600029145"), b'2');
+        // EAN-13 4006381333931
+        assert_eq!(check_digit(b"400638133393"), b'1');
+        // A sum multiple of 10 gives 0
+        assert_eq!(check_digit(b"00000000000"), b'0');
+    }
+
+    #[test]
+    fn product_code_appends_upc_check_digit() {
+        assert_eq!(
+            product_code("03600029145").unwrap(),
+            (Barcode::UpcA, b"036000291452".to_vec())
+        );
+    }
+
+    #[test]
+    fn product_code_accepts_correct_check_digit() {
+        assert_eq!(
+            product_code("036000291452").unwrap(),
+            (Barcode::UpcA, b"036000291452".to_vec())
+        );
+        assert_eq!(
+            product_code("4006381333931").unwrap(),
+            (Barcode::Ean13, b"4006381333931".to_vec())
+        );
+    }
+
+    #[test]
+    fn product_code_rejects_wrong_check_digit() {
+        assert!(product_code("036000291453").is_err());
+        assert!(product_code("4006381333932").is_err());
+    }
+
+    #[test]
+    fn product_code_rejects_invalid_codes() {
+        assert!(product_code("0360002914").is_err());
+        assert!(product_code("40063813339310").is_err());
+        assert!(product_code("4006381333A31").is_err());
+        assert!(product_code("").is_err());
+    }
+
+    #[test]
+    fn product_barcode_restores_size_and_justification() {
+        let sink = VecTransport::new();
+        let printer = Printer::with_transport(sink.clone());
+        printer.set_char_size(2, 1).unwrap();
+        printer.set_justification(Justification::Right).unwrap();
+        sink.take();
+        printer
+            .print_product_barcode("4006381333931", "Pen", "1.99")
+            .unwrap();
+        let bytes = sink.take();
+        assert!(bytes.starts_with(b"\x1ba\x01Pen\n"));
+        assert!(bytes.ends_with(b"\x1d!\x111.99\n\x1d!\x10\x1ba\x02"));
+        assert_eq!(printer.char_size.get(), 0x10);
+        assert_eq!(printer.justification.get(), Justification::Right);
+    }
+
+    #[test]
+    fn code128_code_set() {
+        // Code set B selected when missing
+        let data = barcode_data(Barcode::Code128, "AB-12").unwrap();
+        assert_eq!(data, b"{BAB-12");
+        let data = barcode_data(Barcode::Code128, "{C\x0c\x22").unwrap();
+        assert_eq!(data, b"{C\x0c\x22");
+    }
+
+    #[test]
+    fn barcode_data_checks() {
+        assert!(barcode_data(Barcode::UpcA, "1234567890").is_err());
+        assert!(barcode_data(Barcode::UpcE, "123456789").is_err());
+        assert!(barcode_data(Barcode::Ean13, "40063813339A").is_err());
+        assert!(barcode_data(Barcode::Code39, "abc").is_err());
+        assert!(barcode_data(Barcode::Itf, "123").is_err());
+        assert!(barcode_data(Barcode::Codabar, "A123").is_err());
+        assert!(barcode_data(Barcode::Codabar, "A123B").is_ok());
+        assert!(barcode_data(Barcode::Code93, "").is_err());
+        assert!(barcode_data(Barcode::Code128, &"A".repeat(254)).is_err());
+    }
+
+    #[test]
+    fn barcode_commands() {
+        assert_eq!(
+            barcode_bytes(Barcode::Ean8, "9638507", 50, 3, HriPosition::Above).unwrap(),
+            [
+                &[0x1D, 0x68, 50, 0x1D, 0x77, 3, 0x1D, 0x48, 1][..],
+                &[0x1D, 0x6B, 68, 7],
+                b"9638507",
+            ]
+            .concat()
+        );
+        let bytes = barcode_bytes(Barcode::Itf, "1234", 80, 2, HriPosition::None).unwrap();
+        assert_eq!(&bytes[6..9], [0x1D, 0x48, 0]);
+        assert!(bytes.ends_with(&[0x1D, 0x6B, 70, 4, b'1', b'2', b'3', b'4']));
+    }
+
+    #[test]
+    fn barcode_size_checks() {
+        assert!(barcode_bytes(Barcode::Code39, "A", 0, 2, HriPosition::Below).is_err());
+        assert!(barcode_bytes(Barcode::Code39, "A", 80, 1, HriPosition::Below).is_err());
+        assert!(barcode_bytes(Barcode::Code39, "A", 80, 7, HriPosition::Below).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+use crate::command::finish_commands;
+use crate::{
+    bold_bytes, cut_bytes, feed_lines_bytes, justification_bytes, underline_bytes, CodePage,
+    CutMode, Error, Justification, Printer, UnderlineMode,
+};
+
+/// Commands collected to be sent to the printer at once, see [`Printer::batch`]
+pub struct Batch<'a> {
+    printer: &'a Printer,
+    bytes: Vec<u8>,
+    /// Last justification set, to keep track of the printer's once sent
+    justification: Option<Justification>,
+    /// Code page the text is encoded with, starting with the printer's active one
+    code_page: CodePage,
+    /// Whether a code page was selected, to keep track of the printer's once sent
+    code_page_set: bool,
+}
+
+impl Printer {
+    /// Starts collecting commands, to send them with a single write instead of one per command.
+    ///
+    /// Each write is followed by a pause (see [`Printer::set_op_delay`]): batching a receipt pauses once.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::{CutMode, Justification};
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer
+    ///     .batch()
+    ///     .justification(Justification::Center)
+    ///     .bold(true)
+    ///     .line("MY SHOP")
+    ///     .bold(false)
+    ///     .justification(Justification::Left)
+    ///     .line("Coffee        2.50")
+    ///     .feed_lines(4)
+    ///     .cut(CutMode::Full)
+    ///     .send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn batch(&self) -> Batch<'_> {
+        Batch {
+            printer: self,
+            bytes: Vec::new(),
+            justification: None,
+            code_page: self.code_page.get(),
+            code_page_set: false,
+        }
+    }
+}
+
+impl Batch<'_> {
+    /// Adds raw ESC/POS bytes
+    pub fn raw<A: AsRef<[u8]>>(mut self, bytes: A) -> Self {
+        self.bytes.extend_from_slice(bytes.as_ref());
+        self
+    }
+
+    /// Adds text, without a line feed, encoded with the current code page (see [`Batch::code_page`]). Characters the
+    /// code page doesn't have are replaced with `?`.
+    pub fn text(mut self, text: &str) -> Self {
+        self.bytes.extend(self.code_page.encode(text, b'?'));
+        self
+    }
+
+    /// Adds a line of text, encoded like [`Batch::text`]
+    pub fn line(self, line: &str) -> Self {
+        self.text(line).raw(b"\n")
+    }
+
+    /// Sets the justification, see [`Printer::set_justification`]
+    pub fn justification(mut self, justification: Justification) -> Self {
+        self.justification = Some(justification);
+        self.raw(justification_bytes(justification))
+    }
+
+    /// Selects the code page the following text is encoded with, see [`Printer::set_code_page`]
+    pub fn code_page(mut self, code_page: CodePage) -> Self {
+        self.code_page = code_page;
+        self.code_page_set = true;
+        self.raw([0x1B, 0x74, code_page.number()])
+    }
+
+    /// Turns bold text on or off, see [`Printer::set_bold`]
+    pub fn bold(self, on: bool) -> Self {
+        self.raw(bold_bytes(on))
+    }
+
+    /// Sets the underline mode, see [`Printer::set_underline`]
+    pub fn underline(self, mode: UnderlineMode) -> Self {
+        self.raw(underline_bytes(mode))
+    }
+
+    /// Feeds `n` lines, see [`Printer::feed_lines`]
+    pub fn feed_lines(self, n: u8) -> Self {
+        self.raw(feed_lines_bytes(n))
+    }
+
+    /// Cuts the paper without feeding it, see [`Printer::cut`]
+    pub fn cut(self, mode: CutMode) -> Self {
+        self.raw(cut_bytes(mode))
+    }
+
+    /// Feeds and cuts the paper as configured in the printer's profile, see [`Printer::finish`]
+    pub fn finish(self) -> Self {
+        let commands = finish_commands(&self.printer.profile);
+        self.raw(commands)
+    }
+
+    /// The bytes collected so far
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Sends the collected bytes, with a single write (split into chunks if large, see
+    /// [`Printer::write_raw_timeout`])
+    pub fn send(self) -> Result<(), Error> {
+        self.printer.write_raw(&self.bytes)?;
+        if let Some(justification) = self.justification {
+            self.printer.justification.set(justification);
+        }
+        if self.code_page_set {
+            self.printer.code_page.set(self.code_page);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodePage, Printer, VecTransport};
+
+    #[test]
+    fn text_uses_printer_code_page() {
+        let sink = VecTransport::new();
+        let printer = Printer::with_transport(sink.clone());
+        printer.set_code_page(CodePage::PC850).unwrap();
+        assert_eq!(printer.batch().line("Café €").bytes(), b"Caf\x82 ?\n");
+    }
+
+    #[test]
+    fn code_page_encodes_following_text() {
+        let sink = VecTransport::new();
+        let printer = Printer::with_transport(sink.clone());
+        printer
+            .batch()
+            .text("é")
+            .code_page(CodePage::WPC1252)
+            .text("é €")
+            .send()
+            .unwrap();
+        assert_eq!(sink.take(), b"\x82\x1bt\x10\xe9 \x80");
+        assert_eq!(printer.code_page(), CodePage::WPC1252);
+    }
+}
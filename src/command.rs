@@ -0,0 +1,533 @@
+use crate::{CodePage, Error, Font, Printer, PrinterProfile};
+
+/// Paper cut modes
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CutMode {
+    /// Full cut
+    #[default]
+    Full,
+    /// Partial cut, leaving one point uncut
+    Partial,
+}
+
+/// Text justification
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Justification {
+    /// Left justification
+    #[default]
+    Left,
+    /// Centered
+    Center,
+    /// Right justification
+    Right,
+}
+
+/// Bytes of the justification command (`ESC a n`), see [`Printer::set_justification`]
+/// ```rust
+/// use escpos_rw::{justification_bytes, Justification};
+/// assert_eq!(justification_bytes(Justification::Center), [0x1B, 0x61, 1]);
+/// ```
+pub fn justification_bytes(justification: Justification) -> Vec<u8> {
+    let n = match justification {
+        Justification::Left => 0,
+        Justification::Center => 1,
+        Justification::Right => 2,
+    };
+    vec![0x1B, 0x61, n]
+}
+
+/// Connector pins driving a cash drawer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DrawerPin {
+    /// Drawer kick-out connector pin 2, used by most drawers
+    #[default]
+    Pin2,
+    /// Drawer kick-out connector pin 5, usually a second drawer
+    Pin5,
+}
+
+/// Longest pulse time, in milliseconds, `ESC p` can send
+const MAX_PULSE_MS: u16 = 2 * u8::MAX as u16;
+
+/// Bytes of the cash drawer pulse command (`ESC p`), with times in milliseconds
+fn cash_drawer_bytes(pin: DrawerPin, on_ms: u16, off_ms: u16) -> Result<[u8; 5], Error> {
+    if on_ms > MAX_PULSE_MS || off_ms > MAX_PULSE_MS {
+        return Err(Error::PrinterError(format!(
+            "Cash drawer pulse times must not exceed {} ms, got {} and {} ms",
+            MAX_PULSE_MS, on_ms, off_ms
+        )));
+    }
+    let m = match pin {
+        DrawerPin::Pin2 => 0,
+        DrawerPin::Pin5 => 1,
+    };
+    // Times are in 2 ms units
+    Ok([0x1B, 0x70, m, (on_ms / 2) as u8, (off_ms / 2) as u8])
+}
+
+/// Bytes of the cut command (`GS V m`), see [`Printer::cut`]
+/// ```rust
+/// use escpos_rw::{cut_bytes, CutMode};
+/// assert_eq!(cut_bytes(CutMode::Partial), [0x1D, 0x56, 1]);
+/// ```
+pub fn cut_bytes(mode: CutMode) -> Vec<u8> {
+    let m = match mode {
+        CutMode::Full => 0x00,
+        CutMode::Partial => 0x01,
+    };
+    vec![0x1D, 0x56, m]
+}
+
+/// Bytes of the feed to the cutting position and cut command (`GS V 65 n` or `GS V 66 n`), see
+/// [`Printer::cut_with_feed`]
+pub fn cut_with_feed_bytes(mode: CutMode, lines: u8) -> Vec<u8> {
+    let m = match mode {
+        CutMode::Full => 65,
+        CutMode::Partial => 66,
+    };
+    vec![0x1D, 0x56, m, lines]
+}
+
+/// Bytes of the line feed command (`ESC d n`), see [`Printer::feed_lines`]
+/// ```rust
+/// use escpos_rw::feed_lines_bytes;
+/// assert_eq!(feed_lines_bytes(3), [0x1B, 0x64, 3]);
+/// ```
+pub fn feed_lines_bytes(n: u8) -> Vec<u8> {
+    vec![0x1B, 0x64, n]
+}
+
+/// Bytes of the dot feed command (`ESC J n`), see [`Printer::feed_dots`]
+pub fn feed_dots_bytes(n: u8) -> Vec<u8> {
+    vec![0x1B, 0x4A, n]
+}
+
+/// Feeds lines (`ESC d n`), then cuts (`GS V m`)
+fn feed_and_cut_commands(lines: u8, mode: CutMode) -> Vec<u8> {
+    [feed_lines_bytes(lines), cut_bytes(mode)].concat()
+}
+
+/// Feed and cut commands ending a receipt, as configured in the profile
+pub(crate) fn finish_commands(profile: &PrinterProfile) -> Vec<u8> {
+    feed_and_cut_commands(profile.cut_feed_lines(), profile.cut_mode())
+}
+
+impl Printer {
+    /// Sets the justification of the following lines (`ESC a n`)
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::Justification;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_justification(Justification::Right)?;
+    /// printer.write_raw(b"Total: 2.50\n")?;
+    /// printer.set_justification(Justification::Left)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_justification(&self, justification: Justification) -> Result<(), Error> {
+        self.write_raw(justification_bytes(justification))?;
+        self.justification.set(justification);
+        Ok(())
+    }
+
+    /// Initializes the printer (`ESC @`), back to its power-on settings: text modes, justification, line spacing,
+    /// etc. are reset, and the line buffer is cleared.
+    ///
+    /// User-defined characters and downloaded bit images are cleared as well, unlike what is stored in NV memory.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Don't inherit the settings of the previous job
+    /// printer.initialize()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn initialize(&self) -> Result<(), Error> {
+        self.write_raw([0x1B, 0x40])?;
+        self.justification.set(Justification::default());
+        self.font.set(Font::default());
+        self.code_page.set(CodePage::default());
+        self.char_size.set(0);
+        Ok(())
+    }
+
+    /// Moves the print position back to the beginning of the line, without feeding paper (CR).
+    ///
+    /// Unlike a line feed (LF), which prints the line buffer and feeds paper, this allows overprinting the current
+    /// line, for instance with a line spacing set to zero.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.write_raw(b"VOID")?;
+    /// printer.carriage_return()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn carriage_return(&self) -> Result<(), Error> {
+        self.write_raw([0x0D])
+    }
+
+    /// Prints the line buffer, and feeds `n` lines (`ESC d n`). With 0, the line buffer is printed without feeding.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.write_raw(b"Signature:")?;
+    /// printer.feed_lines(3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn feed_lines(&self, n: u8) -> Result<(), Error> {
+        self.write_raw(feed_lines_bytes(n))
+    }
+
+    /// Prints the line buffer, and feeds one line (`LF`)
+    pub fn newline(&self) -> Result<(), Error> {
+        self.write_raw([0x0A])
+    }
+
+    /// Prints the line buffer, and feeds `n` vertical motion units, a dot on most printers (`ESC J n`).
+    /// With 0, the line buffer is printed without feeding.
+    pub fn feed_dots(&self, n: u8) -> Result<(), Error> {
+        self.write_raw(feed_dots_bytes(n))
+    }
+
+    /// Ends a receipt: feeds the paper and cuts it, as configured in the profile
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::{CutMode, PrinterProfile};
+    /// # let Some(mut printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_profile(
+    ///     PrinterProfile::builder()
+    ///         .cut_feed_lines(5)
+    ///         .cut_mode(CutMode::Partial)
+    ///         .build(),
+    /// );
+    /// printer.write_raw(b"Thank you !\n")?;
+    /// printer.finish()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn finish(&self) -> Result<(), Error> {
+        self.write_raw(finish_commands(&self.profile))
+    }
+
+    /// Cuts the paper (`GS V`), without feeding it first
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::CutMode;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.cut(CutMode::Full)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cut(&self, mode: CutMode) -> Result<(), Error> {
+        self.write_raw(cut_bytes(mode))
+    }
+
+    /// Feeds the paper to the cutting position, plus `lines` vertical motion units (a dot on most printers), then
+    /// cuts it (`GS V 65 n` or `GS V 66 n`).
+    ///
+    /// Unlike [`Printer::cut`], the last printed line is brought past the cutter, whatever the distance between
+    /// the print head and the cutter.
+    pub fn cut_with_feed(&self, mode: CutMode, lines: u8) -> Result<(), Error> {
+        self.write_raw(cut_with_feed_bytes(mode, lines))
+    }
+
+    /// Cuts the paper after feeding the profile's number of lines, adjusted by `offset_lines`.
+    ///
+    /// A positive offset feeds more, when the cut is too close to the last line. A negative offset feeds less,
+    /// when too much blank paper is left, but never less than nothing: the paper isn't fed backwards.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::CutMode;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.write_raw(b"Thank you !\n")?;
+    /// // The default feed cuts through the last line on this printer
+    /// printer.cut_at(1, CutMode::Partial)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cut_at(&self, offset_lines: i8, mode: CutMode) -> Result<(), Error> {
+        let lines =
+            (self.profile.cut_feed_lines() as i16 + offset_lines as i16).clamp(0, u8::MAX as i16);
+        self.write_raw(feed_and_cut_commands(lines as u8, mode))
+    }
+
+    /// Prints pages of ESC/POS bytes, one after the other, feeding and cutting between them as configured in the
+    /// profile if `cut_between` is set. The last page isn't followed by a cut.
+    ///
+    /// Each page is sent as it is, without being copied: only the pages given are held in memory. For very large
+    /// documents, calling this on successive slices of pages avoids rendering all of them beforehand.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let pages = vec![b"Sales: morning\n".to_vec(), b"Sales: afternoon\n".to_vec()];
+    /// printer.print_pages(&pages, true)?;
+    /// printer.finish()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_pages(&self, pages: &[Vec<u8>], cut_between: bool) -> Result<(), Error> {
+        for (i, page) in pages.iter().enumerate() {
+            if i > 0 && cut_between {
+                self.finish()?;
+            }
+            self.write_raw(page)?;
+        }
+        Ok(())
+    }
+
+    /// Opens a cash drawer, sending a pulse on the given pin (`ESC p`).
+    ///
+    /// The pulse is on for `on_ms` and off for `off_ms` milliseconds, rounded down to a multiple of 2 ms. Times are
+    /// sent in 2 ms units, so they can't exceed 510 ms.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::DrawerPin;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.open_cash_drawer(DrawerPin::Pin5, 100, 200)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn open_cash_drawer(&self, pin: DrawerPin, on_ms: u16, off_ms: u16) -> Result<(), Error> {
+        self.write_raw(cash_drawer_bytes(pin, on_ms, off_ms)?)
+    }
+
+    /// Opens the cash drawer on pin 2, with a 252 ms pulse, which suits most drawers
+    pub fn open_cash_drawer_default(&self) -> Result<(), Error> {
+        self.open_cash_drawer(DrawerPin::Pin2, 252, 252)
+    }
+
+    /// Sets the print density (`GS ( K`, function 49): 0 is the standard density, 1 to 6 darker, and 250 to 255
+    /// lighter (-6 to -1).
+    ///
+    /// Support is model dependent: printers ignore the values, or the command, they don't support.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Low contrast paper
+    /// printer.set_print_density(3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_print_density(&self, density: u8) -> Result<(), Error> {
+        if !matches!(density, 0..=6 | 250..=255) {
+            return Err(Error::PrinterError(format!(
+                "Print density must be between 0 and 6, or 250 and 255, got {}",
+                density
+            )));
+        }
+        self.write_raw([0x1D, 0x28, 0x4B, 0x02, 0x00, 0x31, density])
+    }
+
+    /// Sets the print speed (`GS ( K`, function 50): 0 selects the printer's setting, 1 to 13 are speed levels, from
+    /// the slowest to the fastest. Slower printing gives darker, more durable prints.
+    ///
+    /// Support is model dependent: printers ignore the values, or the command, they don't support.
+    pub fn set_print_speed(&self, speed: u8) -> Result<(), Error> {
+        if speed > 13 {
+            return Err(Error::PrinterError(format!(
+                "Print speed must be between 0 and 13, got {}",
+                speed
+            )));
+        }
+        self.write_raw([0x1D, 0x28, 0x4B, 0x02, 0x00, 0x32, speed])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VecTransport;
+
+    #[test]
+    fn cash_drawer_pulse() {
+        assert_eq!(
+            cash_drawer_bytes(DrawerPin::Pin2, 100, 200).unwrap(),
+            [0x1B, 0x70, 0, 50, 100]
+        );
+        // Rounded down to 2 ms
+        assert_eq!(
+            cash_drawer_bytes(DrawerPin::Pin5, 101, 1).unwrap(),
+            [0x1B, 0x70, 1, 50, 0]
+        );
+        assert_eq!(
+            cash_drawer_bytes(DrawerPin::Pin2, 510, 510).unwrap(),
+            [0x1B, 0x70, 0, 255, 255]
+        );
+    }
+
+    #[test]
+    fn cash_drawer_pulse_too_long() {
+        assert!(cash_drawer_bytes(DrawerPin::Pin2, 511, 100).is_err());
+        assert!(cash_drawer_bytes(DrawerPin::Pin2, 100, 512).is_err());
+    }
+
+    fn printer() -> (Printer, VecTransport) {
+        let sink = VecTransport::new();
+        (Printer::with_transport(sink.clone()), sink)
+    }
+
+    #[test]
+    fn carriage_return() {
+        let (printer, sink) = printer();
+        printer.carriage_return().unwrap();
+        assert_eq!(sink.take(), [0x0D]);
+    }
+
+    #[test]
+    fn finish() {
+        let (mut printer, sink) = printer();
+        printer.set_profile(
+            PrinterProfile::builder()
+                .cut_feed_lines(3)
+                .cut_mode(CutMode::Partial)
+                .build(),
+        );
+        printer.finish().unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x64, 3, 0x1D, 0x56, 1]);
+    }
+
+    #[test]
+    fn cut_at() {
+        let (mut printer, sink) = printer();
+        printer.set_profile(PrinterProfile::builder().cut_feed_lines(3).build());
+        printer.cut_at(2, CutMode::Full).unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x64, 5, 0x1D, 0x56, 0]);
+        // Never fed backwards
+        printer.cut_at(-5, CutMode::Partial).unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x64, 0, 0x1D, 0x56, 1]);
+    }
+
+    #[test]
+    fn print_pages() {
+        let (mut printer, sink) = printer();
+        printer.set_profile(PrinterProfile::builder().cut_feed_lines(2).build());
+        printer
+            .print_pages(&[b"A\n".to_vec(), b"B\n".to_vec()], true)
+            .unwrap();
+        assert_eq!(sink.take(), b"A\n\x1bd\x02\x1dV\x00B\n");
+        printer
+            .print_pages(&[b"A\n".to_vec(), b"B\n".to_vec()], false)
+            .unwrap();
+        assert_eq!(sink.take(), b"A\nB\n");
+    }
+
+    #[test]
+    fn cut() {
+        let (printer, sink) = printer();
+        printer.cut(CutMode::Full).unwrap();
+        printer.cut(CutMode::Partial).unwrap();
+        printer.cut_with_feed(CutMode::Full, 0).unwrap();
+        printer.cut_with_feed(CutMode::Partial, 5).unwrap();
+        assert_eq!(
+            sink.take(),
+            [0x1D, 0x56, 0, 0x1D, 0x56, 1, 0x1D, 0x56, 65, 0, 0x1D, 0x56, 66, 5]
+        );
+    }
+
+    #[test]
+    fn initialize_resets_state() {
+        let (printer, sink) = printer();
+        printer.set_justification(Justification::Center).unwrap();
+        printer.set_font(Font::FontB).unwrap();
+        sink.take();
+        printer.initialize().unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x40]);
+        assert_eq!(printer.justification.get(), Justification::Left);
+        assert_eq!(printer.font(), Font::FontA);
+    }
+
+    #[test]
+    fn feed() {
+        let (printer, sink) = printer();
+        printer.feed_lines(3).unwrap();
+        printer.feed_lines(0).unwrap();
+        printer.feed_dots(10).unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x64, 3, 0x1B, 0x64, 0, 0x1B, 0x4A, 10]);
+    }
+
+    #[test]
+    fn justification() {
+        let (printer, sink) = printer();
+        printer.set_justification(Justification::Center).unwrap();
+        printer.set_justification(Justification::Right).unwrap();
+        printer.set_justification(Justification::Left).unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x61, 1, 0x1B, 0x61, 2, 0x1B, 0x61, 0]);
+        assert_eq!(printer.justification.get(), Justification::Left);
+    }
+
+    #[test]
+    fn newline() {
+        let (printer, sink) = printer();
+        printer.newline().unwrap();
+        assert_eq!(sink.take(), [0x0A]);
+    }
+
+    #[test]
+    fn initialize_resets_code_page() {
+        let (printer, _sink) = printer();
+        printer.set_code_page(CodePage::PC850).unwrap();
+        printer.initialize().unwrap();
+        assert_eq!(printer.code_page(), CodePage::PC437);
+    }
+
+    #[test]
+    fn initialize_resets_char_size() {
+        let (printer, _sink) = printer();
+        printer.set_char_size(2, 2).unwrap();
+        printer.initialize().unwrap();
+        assert_eq!(printer.char_size.get(), 0);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::printer::{bulk_endpoint, usb_context};
+use crate::{Error, Printer};
+use rusb::{Direction, UsbContext};
+
+/// A USB device able to receive ESC/POS commands, as found by [`Printer::list`] or given by [`Printer::device_info`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// Vendor id
+    pub vendor_id: u16,
+    /// Product id
+    pub product_id: u16,
+    /// Number of the bus the device is connected to
+    pub bus_number: u8,
+    /// Address of the device on its bus
+    pub address: u8,
+    /// Manufacturer string, if the device could be opened to read it
+    pub manufacturer: Option<String>,
+    /// Product string, if the device could be opened to read it
+    pub product: Option<String>,
+    /// Serial number string, if the device could be opened to read it and has one
+    pub serial: Option<String>,
+}
+
+impl Printer {
+    /// Lists the connected devices having a bulk OUT endpoint, which printers have.
+    ///
+    /// Other devices, like mass storage ones, have such an endpoint too: the manufacturer and product strings
+    /// help telling them apart.
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// for device in Printer::list()? {
+    ///     println!(
+    ///         "{:04x}:{:04x} {}",
+    ///         device.vendor_id,
+    ///         device.product_id,
+    ///         device.product.unwrap_or_default()
+    ///     );
+    /// }
+    /// # Ok(())}
+    /// ```
+    pub fn list() -> Result<Vec<DeviceInfo>, Error> {
+        let context = usb_context()?;
+        let devices = context.devices().map_err(Error::UsbError)?;
+        let mut printers = Vec::new();
+        for device in devices.iter() {
+            let (Ok(descriptor), Ok(config_descriptor)) = (
+                device.device_descriptor(),
+                device.active_config_descriptor(),
+            ) else {
+                continue;
+            };
+            if bulk_endpoint(&config_descriptor, None, Direction::Out).is_none() {
+                continue;
+            }
+            // The strings can only be read once the device is opened, which may not be allowed
+            let handle = device.open().ok();
+            let manufacturer = handle
+                .as_ref()
+                .and_then(|dh| dh.read_manufacturer_string_ascii(&descriptor).ok());
+            let product = handle
+                .as_ref()
+                .and_then(|dh| dh.read_product_string_ascii(&descriptor).ok());
+            let serial = handle
+                .as_ref()
+                .and_then(|dh| dh.read_serial_number_string_ascii(&descriptor).ok());
+            printers.push(DeviceInfo {
+                vendor_id: descriptor.vendor_id(),
+                product_id: descriptor.product_id(),
+                bus_number: device.bus_number(),
+                address: device.address(),
+                manufacturer,
+                product,
+                serial,
+            });
+        }
+        Ok(printers)
+    }
+
+    /// Information about the opened USB printer, read from its descriptors, to tell which unit printed what
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let info = printer.device_info()?;
+    /// println!(
+    ///     "Printing on {} {}, serial {}",
+    ///     info.manufacturer.unwrap_or_default(),
+    ///     info.product.unwrap_or_default(),
+    ///     info.serial.unwrap_or_default()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn device_info(&self) -> Result<DeviceInfo, Error> {
+        let dh = self.device_handle()?;
+        let device = dh.device();
+        let descriptor = device.device_descriptor().map_err(Error::UsbError)?;
+        Ok(DeviceInfo {
+            vendor_id: descriptor.vendor_id(),
+            product_id: descriptor.product_id(),
+            bus_number: device.bus_number(),
+            address: device.address(),
+            manufacturer: dh.read_manufacturer_string_ascii(&descriptor).ok(),
+            product: dh.read_product_string_ascii(&descriptor).ok(),
+            serial: dh.read_serial_number_string_ascii(&descriptor).ok(),
+        })
+    }
+}
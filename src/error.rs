@@ -5,15 +5,41 @@ pub enum Error {
     UsbError(rusb::Error),
     /// This means no bulk endpoint could be found
     NoBulkEndpoint,
-    /// I/O Error
-    IoError,
+    /// No connected device has these vendor and product ids
+    DeviceNotFound {
+        /// Vendor id looked for
+        vendor_id: u16,
+        /// Product id looked for
+        product_id: u16,
+    },
+    /// The printer didn't accept or send data in time
+    Timeout,
+    /// The printer accepted only part of the bytes written
+    ShortWrite {
+        /// Bytes accepted
+        written: usize,
+        /// Bytes written
+        expected: usize,
+    },
+    /// I/O Error, with its kind and message
+    IoError(std::io::ErrorKind, String),
     /// Generic error
     PrinterError(String),
 }
 
 impl From<std::io::Error> for Error {
-    fn from(_e: std::io::Error) -> Error {
-        Error::IoError
+    fn from(e: std::io::Error) -> Error {
+        Error::IoError(e.kind(), e.to_string())
+    }
+}
+
+impl Error {
+    /// Converts an error of a USB transfer, telling timeouts apart
+    pub(crate) fn from_transfer(e: rusb::Error) -> Error {
+        match e {
+            rusb::Error::Timeout => Error::Timeout,
+            e => Error::UsbError(e),
+        }
     }
 }
 
@@ -22,11 +48,29 @@ impl std::fmt::Display for Error {
         let content = match self {
             Error::UsbError(e) => format!("USB error: {}", e),
             Error::NoBulkEndpoint => "No bulk endpoint could be found".to_string(),
-            Error::IoError => format!("I/O Error"),
+            Error::DeviceNotFound {
+                vendor_id,
+                product_id,
+            } => format!(
+                "No printer found with VID/PID {:04x}:{:04x}",
+                vendor_id, product_id
+            ),
+            Error::Timeout => "Timeout while communicating with the printer".to_string(),
+            Error::ShortWrite { written, expected } => {
+                format!("The printer accepted {} bytes out of {}", written, expected)
+            }
+            Error::IoError(_, detail) => format!("I/O Error: {}", detail),
             Error::PrinterError(detail) => format!("Printer error: {}", detail),
         };
         write!(formatter, "{}", content)
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::UsbError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
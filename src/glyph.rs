@@ -0,0 +1,101 @@
+use crate::{Error, Printer};
+
+/// Height of user-defined characters, in bytes of 8 dots: 24 dots, the height of font A
+const GLYPH_HEIGHT_BYTES: usize = 3;
+/// Maximum width of user-defined characters, in dots: the width of font A
+const GLYPH_MAX_WIDTH: u8 = 12;
+
+/// A user-defined character, 24 dots high, for [`Printer::define_user_chars`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserGlyph {
+    /// Width in dots
+    width: u8,
+    /// Column format bitmap
+    data: Vec<u8>,
+}
+
+impl UserGlyph {
+    /// Creates a glyph, `width` dots wide (up to 12), from its bitmap in column format: for each column, 3 bytes of 8
+    /// vertical dots each, the most significant bit on top
+    /// ```rust
+    /// use escpos_rw::UserGlyph;
+    /// // A 2 dots wide vertical bar
+    /// let glyph = UserGlyph::new(2, vec![0xFF; 6]).unwrap();
+    /// assert!(UserGlyph::new(2, vec![0xFF; 5]).is_err());
+    /// ```
+    pub fn new(width: u8, data: Vec<u8>) -> Result<UserGlyph, Error> {
+        if width > GLYPH_MAX_WIDTH {
+            return Err(Error::PrinterError(format!(
+                "User-defined characters are at most {} dots wide, got {}",
+                GLYPH_MAX_WIDTH, width
+            )));
+        }
+        if data.len() != width as usize * GLYPH_HEIGHT_BYTES {
+            return Err(Error::PrinterError(format!(
+                "A {} dots wide user-defined character has {} bytes, got {}",
+                width,
+                width as usize * GLYPH_HEIGHT_BYTES,
+                data.len()
+            )));
+        }
+        Ok(UserGlyph { width, data })
+    }
+
+    /// Width in dots
+    pub fn width(&self) -> u8 {
+        self.width
+    }
+}
+
+impl Printer {
+    /// Defines the characters from `start` to `end` (`ESC & y c1 c2`), between 32 and 126, with one glyph each.
+    ///
+    /// They are printed instead of the code page characters once enabled with [`Printer::enable_user_chars`], and
+    /// stay defined until the printer is initialized or switched off.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::UserGlyph;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// # let currency_symbol = vec![0; 36];
+    /// printer.define_user_chars(b'~', b'~', &[UserGlyph::new(12, currency_symbol)?])?;
+    /// printer.enable_user_chars(true)?;
+    /// printer.write_raw(b"Total: 12.50 ~\n")?;
+    /// printer.enable_user_chars(false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn define_user_chars(&self, start: u8, end: u8, glyphs: &[UserGlyph]) -> Result<(), Error> {
+        if !(32..=126).contains(&start) || !(start..=126).contains(&end) {
+            return Err(Error::PrinterError(format!(
+                "User-defined characters must be between 32 and 126, in order, got {} to {}",
+                start, end
+            )));
+        }
+        let count = (end - start) as usize + 1;
+        if glyphs.len() != count {
+            return Err(Error::PrinterError(format!(
+                "{} glyphs are needed for characters {} to {}, got {}",
+                count,
+                start,
+                end,
+                glyphs.len()
+            )));
+        }
+        let mut bytes = vec![0x1B, 0x26, GLYPH_HEIGHT_BYTES as u8, start, end];
+        for glyph in glyphs {
+            bytes.push(glyph.width);
+            bytes.extend_from_slice(&glyph.data);
+        }
+        self.write_raw(bytes)
+    }
+
+    /// Prints the user-defined characters instead of the code page ones, or stops doing so (`ESC % n`)
+    pub fn enable_user_chars(&self, on: bool) -> Result<(), Error> {
+        self.write_raw([0x1B, 0x25, on as u8])
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Error, Printer};
+
+/// Printer ids requested with `GS I n`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdKind {
+    /// Model id, a single byte (n = 1)
+    Model,
+    /// Type id, a single byte decoded by [`PrinterType::from_byte`] (n = 2)
+    Type,
+    /// Version id, a single byte (n = 3)
+    Version,
+    /// Firmware version, as text (n = 65)
+    Firmware,
+    /// Maker name, as text (n = 66)
+    Maker,
+    /// Model name, as text (n = 67)
+    ModelName,
+    /// Serial number, as text (n = 68)
+    SerialNumber,
+    /// Supported languages of the fonts, as text (n = 69)
+    FontLanguage,
+}
+
+impl IdKind {
+    fn as_byte(self) -> u8 {
+        match self {
+            IdKind::Model => 1,
+            IdKind::Type => 2,
+            IdKind::Version => 3,
+            IdKind::Firmware => 65,
+            IdKind::Maker => 66,
+            IdKind::ModelName => 67,
+            IdKind::SerialNumber => 68,
+            IdKind::FontLanguage => 69,
+        }
+    }
+
+    /// Text ids are sent back as `_`, the text, then NUL
+    fn is_text(self) -> bool {
+        self.as_byte() >= 65
+    }
+}
+
+/// Maximum length of a text id, header and NUL included
+const TEXT_ID_MAX_LEN: usize = 82;
+
+/// Decoded type id, to adapt to what the printer can do
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrinterType {
+    /// Two bytes character codes (Japanese, Chinese, Korean) are supported
+    pub multibyte: bool,
+    /// An autocutter is installed: [`Printer::cut`] can be used
+    pub autocutter: bool,
+    /// A customer display is connected
+    pub customer_display: bool,
+}
+
+impl PrinterType {
+    /// Decodes the type id sent back by the printer in reply to `GS I 2`
+    /// ```rust
+    /// use escpos_rw::PrinterType;
+    /// let printer_type = PrinterType::from_byte(0x02);
+    /// assert!(printer_type.autocutter);
+    /// assert!(!printer_type.multibyte);
+    /// ```
+    pub fn from_byte(byte: u8) -> PrinterType {
+        let bit = |n: u8| byte & (1 << n) != 0;
+        PrinterType {
+            multibyte: bit(0),
+            autocutter: bit(1),
+            customer_display: bit(2),
+        }
+    }
+}
+
+impl Printer {
+    /// Requests a printer id (`GS I n`), and returns it: the id byte, or the text without its `_` header and NUL
+    /// terminator.
+    ///
+    /// Not all printers support all ids, especially the text ones: such requests time out.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::IdKind;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let firmware = printer.printer_id(IdKind::Firmware)?;
+    /// println!("Firmware {}", String::from_utf8_lossy(&firmware));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn printer_id(&self, kind: IdKind) -> Result<Vec<u8>, Error> {
+        self.write_raw([0x1D, 0x49, kind.as_byte()])?;
+        if !kind.is_text() {
+            return self.read_until(0x00, 1, self.read_timeout());
+        }
+        let response = self.read_until(0x00, TEXT_ID_MAX_LEN, self.read_timeout())?;
+        match response.as_slice() {
+            [b'_', text @ .., 0x00] => Ok(text.to_vec()),
+            _ => Err(Error::PrinterError(format!(
+                "Invalid printer id: {:02x?}",
+                response
+            ))),
+        }
+    }
+
+    /// Requests the type id (`GS I 2`), and decodes it
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::CutMode;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// if printer.printer_type()?.autocutter {
+    ///     printer.cut(CutMode::Partial)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn printer_type(&self) -> Result<PrinterType, Error> {
+        let id = self.printer_id(IdKind::Type)?;
+        Ok(PrinterType::from_byte(id[0]))
+    }
+}
@@ -0,0 +1,618 @@
+use crate::{Error, Printer};
+
+/// Byte order of the width and height parameters of raster image commands.
+///
+/// The ESC/POS standard is low byte first. Some clone printers expect the high byte first and print garbled
+/// images otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RasterByteOrder {
+    /// Low byte first, as specified by ESC/POS
+    #[default]
+    LittleEndian,
+    /// High byte first, for noncompliant clones
+    BigEndian,
+}
+
+impl RasterByteOrder {
+    fn encode(self, value: u16) -> [u8; 2] {
+        match self {
+            RasterByteOrder::LittleEndian => value.to_le_bytes(),
+            RasterByteOrder::BigEndian => value.to_be_bytes(),
+        }
+    }
+}
+
+/// Print modes of downloaded and NV bit images
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BitImageMode {
+    /// Normal size
+    #[default]
+    Normal,
+    /// Double width
+    DoubleWidth,
+    /// Double height
+    DoubleHeight,
+    /// Double width and height
+    Quadruple,
+}
+
+/// Conversion of shades of gray to black and white dots
+#[cfg(feature = "image")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Dither {
+    /// Dots darker than middle gray are black. Best for logos and text.
+    Threshold,
+    /// Floyd–Steinberg error diffusion, rendering shades with dot patterns. Best for photos.
+    #[default]
+    FloydSteinberg,
+}
+
+/// A monochrome bitmap, ready to be sent to the printer.
+///
+/// Pixels are stored row by row, 8 pixels per byte with the most significant bit on the left.
+/// Each row is padded to a whole number of bytes. A set bit prints a black dot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscposImage {
+    /// Width in dots
+    width: u32,
+    /// Height in dots
+    height: u32,
+    /// Packed raster data
+    data: Vec<u8>,
+}
+
+impl EscposImage {
+    /// Creates a blank (white) image of the given size in dots
+    /// ```rust
+    /// use escpos_rw::EscposImage;
+    /// let image = EscposImage::new(12, 2);
+    /// assert_eq!(image.bytes_per_row(), 2);
+    /// assert_eq!(image.raster(), &[0, 0, 0, 0]);
+    /// ```
+    pub fn new(width: u32, height: u32) -> EscposImage {
+        let data = vec![0; width.div_ceil(8) as usize * height as usize];
+        EscposImage {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Creates an image from already packed raster data
+    /// ```rust
+    /// use escpos_rw::EscposImage;
+    /// # fn main() -> Result<(), escpos_rw::Error> {
+    /// // A 10x2 image: one black dot in each corner of the first row
+    /// let image = EscposImage::from_raster(10, 2, vec![0x80, 0x40, 0x00, 0x00])?;
+    /// assert!(image.pixel(0, 0));
+    /// assert!(image.pixel(9, 0));
+    /// assert!(!image.pixel(0, 1));
+    /// # Ok(())}
+    /// ```
+    pub fn from_raster(width: u32, height: u32, data: Vec<u8>) -> Result<EscposImage, Error> {
+        let expected = width.div_ceil(8) as usize * height as usize;
+        if data.len() != expected {
+            return Err(Error::PrinterError(format!(
+                "Raster data is {} bytes long, expected {} for a {}x{} image",
+                data.len(),
+                expected,
+                width,
+                height
+            )));
+        }
+        Ok(EscposImage {
+            width,
+            height,
+            data,
+        })
+    }
+
+    /// Width of the image, in dots
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height of the image, in dots
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Number of bytes used by each row of the raster data
+    pub fn bytes_per_row(&self) -> usize {
+        self.width.div_ceil(8) as usize
+    }
+
+    /// Packed raster data, row by row
+    pub fn raster(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns true if the dot at the given position is black. Out of bounds dots are white.
+    pub fn pixel(&self, x: u32, y: u32) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        let byte = self.data[y as usize * self.bytes_per_row() + x as usize / 8];
+        byte & (0x80 >> (x % 8)) != 0
+    }
+
+    /// Sets the dot at the given position. Out of bounds positions are ignored.
+    pub fn set_pixel(&mut self, x: u32, y: u32, black: bool) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let index = y as usize * self.bytes_per_row() + x as usize / 8;
+        let mask = 0x80 >> (x % 8);
+        if black {
+            self.data[index] |= mask;
+        } else {
+            self.data[index] &= !mask;
+        }
+    }
+
+    /// Image data in column format: for each column, `height_bytes` bytes of 8 vertical dots each,
+    /// the most significant bit on top
+    fn columns(&self, height_bytes: u32) -> Vec<u8> {
+        let mut data = Vec::with_capacity((self.width * height_bytes) as usize);
+        for x in 0..self.width.div_ceil(8) * 8 {
+            for row in 0..height_bytes {
+                let mut byte = 0;
+                for bit in 0..8 {
+                    if self.pixel(x, row * 8 + bit) {
+                        byte |= 0x80 >> bit;
+                    }
+                }
+                data.push(byte);
+            }
+        }
+        data
+    }
+
+    /// Converts an image of the [image](https://docs.rs/image) crate, scaled down to `max_width` dots if wider.
+    ///
+    /// Transparent pixels are white.
+    /// ```rust,no_run
+    /// use escpos_rw::{Dither, Error, EscposImage};
+    /// # fn main() -> Result<(), Error> {
+    /// # let png = Vec::new();
+    /// let photo = image::load_from_memory(&png)
+    ///     .map_err(|e| Error::PrinterError(e.to_string()))?;
+    /// let image = EscposImage::from_dynamic_image(&photo, 576, Dither::FloydSteinberg)?;
+    /// # Ok(())}
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn from_dynamic_image(
+        img: &::image::DynamicImage,
+        max_width: u32,
+        dither: Dither,
+    ) -> Result<EscposImage, Error> {
+        use ::image::imageops::FilterType;
+
+        if max_width == 0 {
+            return Err(Error::PrinterError(
+                "Image width must not be zero".to_string(),
+            ));
+        }
+        if img.width() == 0 || img.height() == 0 {
+            return Err(Error::PrinterError("Image is empty".to_string()));
+        }
+        let img = if img.width() > max_width {
+            let height = (img.height() as u64 * max_width as u64 / img.width() as u64).max(1);
+            img.resize_exact(max_width, height as u32, FilterType::Triangle)
+        } else {
+            img.clone()
+        };
+
+        let (width, height) = (img.width(), img.height());
+        // Gray levels, blended over white paper
+        let mut levels: Vec<i32> = img
+            .to_luma_alpha8()
+            .pixels()
+            .map(|p| {
+                let [luma, alpha] = p.0;
+                (luma as i32 * alpha as i32 / 255) + (255 - alpha as i32)
+            })
+            .collect();
+
+        let mut image = EscposImage::new(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let index = (y * width + x) as usize;
+                let level = levels[index];
+                let black = level < 128;
+                image.set_pixel(x, y, black);
+                if dither == Dither::Threshold {
+                    continue;
+                }
+                // Spreads the error over the neighbours not processed yet: 7/16 right, 3/16 below left,
+                // 5/16 below and 1/16 below right
+                let error = level - if black { 0 } else { 255 };
+                let mut spread = |dx: i64, dy: u32, weight: i32| {
+                    let (nx, ny) = (x as i64 + dx, y + dy);
+                    if nx >= 0 && nx < width as i64 && ny < height {
+                        levels[(ny * width) as usize + nx as usize] += error * weight / 16;
+                    }
+                };
+                spread(1, 0, 7);
+                spread(-1, 1, 3);
+                spread(0, 1, 5);
+                spread(1, 1, 1);
+            }
+        }
+        Ok(image)
+    }
+
+    /// Renders text with a TrueType/OpenType font into an image `width_dots` wide.
+    ///
+    /// Lines are word-wrapped to the image width, and the image is as tall as the rendered text.
+    /// Useful to print typefaces or sizes the printer hardware doesn't offer.
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, EscposImage};
+    /// # fn main() -> Result<(), Error> {
+    /// let font = std::fs::read("DejaVuSans.ttf")?;
+    /// let image = EscposImage::from_text("Café de la Gare", &font, 48.0, 576)?;
+    /// # Ok(())}
+    /// ```
+    #[cfg(feature = "render-text")]
+    pub fn from_text(
+        text: &str,
+        font_bytes: &[u8],
+        px_height: f32,
+        width_dots: u32,
+    ) -> Result<EscposImage, Error> {
+        use fontdue::layout::{CoordinateSystem, Layout, LayoutSettings, TextStyle};
+
+        if width_dots == 0 {
+            return Err(Error::PrinterError(
+                "Image width must not be zero".to_string(),
+            ));
+        }
+        let font = fontdue::Font::from_bytes(font_bytes, fontdue::FontSettings::default())
+            .map_err(|e| Error::PrinterError(format!("Invalid font: {}", e)))?;
+
+        let mut layout = Layout::new(CoordinateSystem::PositiveYDown);
+        layout.reset(&LayoutSettings {
+            max_width: Some(width_dots as f32),
+            ..LayoutSettings::default()
+        });
+        layout.append(&[&font], &TextStyle::new(text, px_height, 0));
+
+        let height = layout.height().ceil() as u32;
+        if height == 0 {
+            return Err(Error::PrinterError(
+                "Text renders to an empty image".to_string(),
+            ));
+        }
+
+        let mut image = EscposImage::new(width_dots, height);
+        for glyph in layout.glyphs() {
+            if glyph.width == 0 || glyph.height == 0 {
+                continue;
+            }
+            let (metrics, coverage) = font.rasterize_config(glyph.key);
+            let left = glyph.x.round() as i64;
+            let top = glyph.y.round() as i64;
+            for row in 0..metrics.height {
+                for col in 0..metrics.width {
+                    // Anything at least half covered becomes a black dot
+                    if coverage[row * metrics.width + col] < 128 {
+                        continue;
+                    }
+                    let (x, y) = (left + col as i64, top + row as i64);
+                    if x >= 0 && y >= 0 {
+                        image.set_pixel(x as u32, y as u32, true);
+                    }
+                }
+            }
+        }
+        Ok(image)
+    }
+}
+
+impl Printer {
+    /// Sets the byte order used for image dimensions. Only needed for some noncompliant clone printers.
+    pub fn set_raster_byte_order(&mut self, order: RasterByteOrder) {
+        self.raster_byte_order = order;
+    }
+
+    /// Sets the maximum height, in dots, of the bands images are split into (128 by default).
+    ///
+    /// Lower it if tall images get truncated: some printers can't buffer a whole image sent as a single command.
+    pub fn set_image_band_height(&mut self, rows: u16) {
+        self.image_band_height = rows.max(1);
+    }
+
+    /// Prints an image with `GS v 0` raster bit image commands.
+    ///
+    /// Tall images are split into horizontal bands, each one sent as its own command. Images wider than the profile
+    /// width are rejected, as the printer would garble them.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::EscposImage;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // A 64x64 black square
+    /// let image = EscposImage::from_raster(64, 64, vec![0xFF; 8 * 64])?;
+    /// printer.print_image(&image)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_image(&self, image: &EscposImage) -> Result<(), Error> {
+        let Ok(width) = u16::try_from(image.bytes_per_row()) else {
+            return Err(Error::PrinterError(format!(
+                "Image is too wide: {} dots",
+                image.width()
+            )));
+        };
+        if width == 0 || image.height() == 0 {
+            return Err(Error::PrinterError("Image is empty".to_string()));
+        }
+        if image.width() > self.profile.width() as u32 {
+            return Err(Error::PrinterError(format!(
+                "Image is {} dots wide, the printable width is {} dots",
+                image.width(),
+                self.profile.width()
+            )));
+        }
+        let band_size = image.bytes_per_row() * self.image_band_height as usize;
+        for band in image.raster().chunks(band_size) {
+            let height = (band.len() / image.bytes_per_row()) as u16;
+            let mut bytes = vec![0x1D, 0x76, 0x30, 0x00];
+            bytes.extend_from_slice(&self.raster_byte_order.encode(width));
+            bytes.extend_from_slice(&self.raster_byte_order.encode(height));
+            bytes.extend_from_slice(band);
+            self.write_raw(bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Stores an image in the printer's RAM (`GS *`), to print it quickly with [`Printer::print_downloaded_bitimage`].
+    ///
+    /// The image stays defined until it is redefined, the printer initialized, or switched off. Its size,
+    /// rounded up to multiples of 8 dots, must not exceed 2040 dots horizontally, 384 dots vertically, and 1536
+    /// bytes in total.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::{BitImageMode, EscposImage};
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let logo = EscposImage::from_raster(64, 64, vec![0xFF; 8 * 64])?;
+    /// printer.define_downloaded_bitimage(&logo)?;
+    /// // Then, for each receipt
+    /// printer.print_downloaded_bitimage(BitImageMode::Normal)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn define_downloaded_bitimage(&self, image: &EscposImage) -> Result<(), Error> {
+        let x = image.width().div_ceil(8);
+        let y = image.height().div_ceil(8);
+        if x == 0 || y == 0 || x > 255 || y > 48 || x * y > 1536 {
+            return Err(Error::PrinterError(format!(
+                "Image size {}x{} exceeds the downloaded bit image limits",
+                image.width(),
+                image.height()
+            )));
+        }
+        let mut bytes = vec![0x1D, 0x2A, x as u8, y as u8];
+        bytes.extend_from_slice(&image.columns(y));
+        self.write_raw(bytes)
+    }
+
+    /// Prints the image stored by [`Printer::define_downloaded_bitimage`] (`GS /`)
+    pub fn print_downloaded_bitimage(&self, mode: BitImageMode) -> Result<(), Error> {
+        let m = match mode {
+            BitImageMode::Normal => 0,
+            BitImageMode::DoubleWidth => 1,
+            BitImageMode::DoubleHeight => 2,
+            BitImageMode::Quadruple => 3,
+        };
+        self.write_raw([0x1D, 0x2F, m])
+    }
+
+    /// Stores an image in the printer's NV (non-volatile) memory (`GS ( L` or `GS 8 L`, function 67), under the
+    /// given key, to print it with [`Printer::print_nv_bitmap`]. The image is kept when the printer is switched off.
+    ///
+    /// The key is made of two printable ASCII characters (32 to 126). NV memory is flash memory, which wears out:
+    /// define images once, when installing the printer, not for each receipt. The printer is busy while writing, and
+    /// Epson recommends at most 10 writes a day.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::{BitImageMode, EscposImage};
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Once
+    /// let logo = EscposImage::from_raster(64, 64, vec![0xFF; 8 * 64])?;
+    /// printer.define_nv_bitmap((b'L', b'1'), &logo)?;
+    /// // Then, for each receipt
+    /// printer.print_nv_bitmap((b'L', b'1'), BitImageMode::Normal)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn define_nv_bitmap(&self, key: (u8, u8), image: &EscposImage) -> Result<(), Error> {
+        check_nv_key(key)?;
+        if image.width() == 0
+            || image.height() == 0
+            || image.width() > 8192
+            || image.height() > 2304
+        {
+            return Err(Error::PrinterError(format!(
+                "Image size {}x{} exceeds the NV graphics limits",
+                image.width(),
+                image.height()
+            )));
+        }
+        let [x_l, x_h] = (image.width() as u16).to_le_bytes();
+        let [y_l, y_h] = (image.height() as u16).to_le_bytes();
+        // Monochrome raster, one color
+        let parameters = [
+            0x30, 0x43, 0x30, key.0, key.1, 0x01, x_l, x_h, y_l, y_h, 0x31,
+        ];
+        let length = parameters.len() + image.raster().len();
+        // The 4 bytes length form is needed for large images
+        let mut bytes = match u16::try_from(length) {
+            Ok(length) => [&[0x1D, 0x28, 0x4C][..], &length.to_le_bytes()].concat(),
+            Err(_) => [&[0x1D, 0x38, 0x4C][..], &(length as u32).to_le_bytes()].concat(),
+        };
+        bytes.extend_from_slice(&parameters);
+        bytes.extend_from_slice(image.raster());
+        self.write_raw(bytes)
+    }
+
+    /// Prints an image stored with [`Printer::define_nv_bitmap`] (`GS ( L`, function 69)
+    pub fn print_nv_bitmap(&self, key: (u8, u8), mode: BitImageMode) -> Result<(), Error> {
+        check_nv_key(key)?;
+        let (x, y) = match mode {
+            BitImageMode::Normal => (1, 1),
+            BitImageMode::DoubleWidth => (2, 1),
+            BitImageMode::DoubleHeight => (1, 2),
+            BitImageMode::Quadruple => (2, 2),
+        };
+        self.write_raw([0x1D, 0x28, 0x4C, 0x06, 0x00, 0x30, 0x45, key.0, key.1, x, y])
+    }
+}
+
+/// Checks an NV graphics key is made of printable ASCII characters
+fn check_nv_key(key: (u8, u8)) -> Result<(), Error> {
+    if !(32..=126).contains(&key.0) || !(32..=126).contains(&key.1) {
+        return Err(Error::PrinterError(format!(
+            "NV graphics key bytes must be between 32 and 126, got ({}, {})",
+            key.0, key.1
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VecTransport;
+
+    fn printer() -> (Printer, VecTransport) {
+        let sink = VecTransport::new();
+        (Printer::with_transport(sink.clone()), sink)
+    }
+
+    #[test]
+    fn big_endian_dimensions() {
+        let (mut printer, sink) = printer();
+        printer.set_raster_byte_order(RasterByteOrder::BigEndian);
+        let image = EscposImage::new(8, 300);
+        printer.print_image(&image).unwrap();
+        // Default bands of 128 rows: 128, 128 and 44 rows
+        let bytes = sink.take();
+        assert_eq!(bytes[..8], [0x1D, 0x76, 0x30, 0x00, 0, 1, 0, 128]);
+        assert_eq!(bytes.len(), 3 * 8 + 300);
+        assert_eq!(
+            bytes[2 * (8 + 128)..][..8],
+            [0x1D, 0x76, 0x30, 0x00, 0, 1, 0, 44]
+        );
+    }
+
+    #[test]
+    fn tall_image_bands() {
+        let (mut printer, sink) = printer();
+        printer.set_image_band_height(2);
+        let image = EscposImage::from_raster(8, 5, vec![1, 2, 3, 4, 5]).unwrap();
+        printer.print_image(&image).unwrap();
+        assert_eq!(
+            sink.take(),
+            [
+                &[0x1D, 0x76, 0x30, 0x00, 1, 0, 2, 0, 1, 2][..],
+                &[0x1D, 0x76, 0x30, 0x00, 1, 0, 2, 0, 3, 4],
+                &[0x1D, 0x76, 0x30, 0x00, 1, 0, 1, 0, 5],
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn single_band() {
+        let (printer, sink) = printer();
+        let image = EscposImage::from_raster(16, 2, vec![0xF0, 0x0F, 0xAA, 0x55]).unwrap();
+        printer.print_image(&image).unwrap();
+        assert_eq!(
+            sink.take(),
+            [0x1D, 0x76, 0x30, 0x00, 2, 0, 2, 0, 0xF0, 0x0F, 0xAA, 0x55]
+        );
+    }
+
+    #[test]
+    fn rejected_images() {
+        let (printer, sink) = printer();
+        // Wider than the default 576 dots profile
+        assert!(printer.print_image(&EscposImage::new(584, 1)).is_err());
+        assert!(printer.print_image(&EscposImage::new(0, 1)).is_err());
+        assert!(printer.print_image(&EscposImage::new(8, 0)).is_err());
+        assert!(sink.bytes().is_empty());
+    }
+
+    #[cfg(feature = "image")]
+    fn convert(luma: u8, alpha: u8, dither: Dither) -> EscposImage {
+        let img = ::image::GrayAlphaImage::from_pixel(8, 4, ::image::LumaA([luma, alpha]));
+        EscposImage::from_dynamic_image(&img.into(), 576, dither).unwrap()
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn convert_white_black_and_transparent() {
+        for dither in [Dither::Threshold, Dither::FloydSteinberg] {
+            assert_eq!(
+                convert(255, 255, dither).raster(),
+                [0x00; 4],
+                "{:?}",
+                dither
+            );
+            assert_eq!(convert(0, 255, dither).raster(), [0xFF; 4], "{:?}", dither);
+            // Transparent pixels are white, whatever their gray level
+            assert_eq!(convert(0, 0, dither).raster(), [0x00; 4], "{:?}", dither);
+        }
+        let white = ::image::GrayImage::from_pixel(4, 4, ::image::Luma([255]));
+        let image = EscposImage::from_dynamic_image(&white.into(), 576, Dither::Threshold).unwrap();
+        assert_eq!(image.raster(), [0x00; 4]);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn convert_gray() {
+        // Dark and light grays go to the nearest of black and white
+        assert_eq!(convert(100, 255, Dither::Threshold).raster(), [0xFF; 4]);
+        assert_eq!(convert(160, 255, Dither::Threshold).raster(), [0x00; 4]);
+        // Half transparent black is a light gray
+        assert_eq!(convert(0, 100, Dither::Threshold).raster(), [0x00; 4]);
+        // Error diffusion renders mid-gray with about half the dots black
+        let image = convert(128, 255, Dither::FloydSteinberg);
+        let black: u32 = image.raster().iter().map(|b| b.count_ones()).sum();
+        assert!((12..=20).contains(&black), "{} black dots", black);
+        assert_ne!(image.raster(), [0x00; 4]);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn convert_scales_down() {
+        let img = ::image::GrayImage::from_pixel(32, 8, ::image::Luma([0]));
+        let image = EscposImage::from_dynamic_image(&img.into(), 16, Dither::Threshold).unwrap();
+        assert_eq!((image.width(), image.height()), (16, 4));
+        assert_eq!(image.raster(), [0xFF; 8]);
+        let empty = ::image::GrayImage::new(0, 0);
+        assert!(EscposImage::from_dynamic_image(&empty.into(), 16, Dither::Threshold).is_err());
+    }
+
+    #[cfg(feature = "render-text")]
+    #[test]
+    fn render_text_errors() {
+        assert!(EscposImage::from_text("Hello", b"not a font", 24.0, 384).is_err());
+        assert!(EscposImage::from_text("Hello", b"not a font", 24.0, 0).is_err());
+    }
+}
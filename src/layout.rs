@@ -0,0 +1,395 @@
+use crate::{text, CodePage, Error, Font, Justification, Printer};
+
+/// Divider line with a centered label encoded with `codepage`, `columns` wide, line feed included
+pub(crate) fn section_line(label: &str, columns: u8, codepage: CodePage) -> Vec<u8> {
+    let columns = columns as usize;
+    let mut label = codepage.encode(label, b'?');
+    // The label is surrounded by spaces
+    label.truncate(columns.saturating_sub(2));
+    let mut line = if label.is_empty() {
+        vec![b'-'; columns]
+    } else {
+        let dashes = columns.saturating_sub(label.len() + 2);
+        let left = dashes / 2;
+        let mut line = vec![b'-'; left];
+        line.push(b' ');
+        line.extend(label);
+        line.push(b' ');
+        line.extend(std::iter::repeat_n(b'-', dashes - left));
+        line
+    };
+    line.push(b'\n');
+    line
+}
+
+impl Printer {
+    /// Prints a divider line with a centered label, like `----- ITEMS -----`, as wide as the profile allows with the
+    /// selected font and the character width set with [`Printer::set_char_size`].
+    ///
+    /// The label is encoded with the active code page (see [`Printer::code_page`]), and truncated if too long for
+    /// the line.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.section("ITEMS")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn section(&self, label: &str) -> Result<(), Error> {
+        self.write_raw(section_line(
+            label,
+            self.line_columns(self.font.get()),
+            self.code_page.get(),
+        ))
+    }
+
+    /// Prints a divider line repeating `ch`, as wide as the profile allows with the given font and the character
+    /// width set with [`Printer::set_char_size`], so that it never wraps. The previous font is selected again
+    /// afterwards.
+    ///
+    /// `ch` is encoded with the code page selected with [`Printer::set_code_page`], and printed as `?` if it
+    /// doesn't have it.
+    /// ```rust
+    /// use escpos_rw::{Error, Font, Printer, PrinterProfile, VecTransport};
+    /// # fn main() -> Result<(), Error> {
+    /// let sink = VecTransport::new();
+    /// let mut printer = Printer::with_transport(sink.clone());
+    /// printer.set_profile(PrinterProfile::builder().width(384).build());
+    /// printer.print_divider('=', Font::FontA)?;
+    /// // 32 columns of 12 dots
+    /// assert_eq!(sink.bytes().iter().filter(|b| **b == b'=').count(), 32);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_divider(&self, ch: char, font: Font) -> Result<(), Error> {
+        let previous = self.font.get();
+        self.set_font(font)?;
+        let mut line = self
+            .code_page
+            .get()
+            .encode(&ch.to_string(), b'?')
+            .repeat(self.line_columns(font) as usize);
+        line.push(b'\n');
+        let printed = self.write_raw(line);
+        let restored = self.set_font(previous);
+        printed.and(restored)
+    }
+
+    /// Prints a line with the given justification, then restores the previous one, even if printing failed.
+    /// The line is encoded with the active code page, see [`Printer::code_page`].
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::Justification;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.write_raw(b"Coffee\n")?;
+    /// printer.println_justified("Total: 2.50", Justification::Right)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn println_justified(&self, text: &str, justification: Justification) -> Result<(), Error> {
+        let previous = self.justification.get();
+        self.set_justification(justification)?;
+        let mut line = self.code_page.get().encode(text, b'?');
+        line.push(b'\n');
+        let printed = self.write_raw(line);
+        let restored = self.set_justification(previous);
+        printed.and(restored)
+    }
+
+    /// Prints text with the given font, word-wrapped to the columns the profile gives for it, divided by the
+    /// character width set with [`Printer::set_char_size`].
+    ///
+    /// Text is encoded with the active code page (see [`Printer::code_page`]), and wrapped once encoded, one
+    /// character per byte. Words longer than a line are split. The previous font is selected again afterwards.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::Font;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.print_wrapped(
+    ///     "Organic fair trade coffee beans, medium roast, 1kg bag",
+    ///     Font::FontA,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_wrapped(&self, text: &str, font: Font) -> Result<(), Error> {
+        let previous = self.font.get();
+        self.set_font(font)?;
+        let mut bytes = Vec::new();
+        for line in text::wrap(text, self.line_columns(font) as usize, self.code_page.get()) {
+            bytes.extend(line);
+            bytes.push(b'\n');
+        }
+        let printed = self.write_raw(bytes);
+        let restored = self.set_font(previous);
+        printed.and(restored)
+    }
+
+    /// Prints a line with the given font, `left` on the left and `right` on the right, like an item and its price.
+    ///
+    /// Both are encoded with the active code page (see [`Printer::code_page`]), and padded once encoded, so that
+    /// characters it doesn't have, printed as `?`, don't shift the columns. If both don't fit, `left` is truncated
+    /// with an ellipsis. The line is as wide as the profile allows with the font and the character width set with
+    /// [`Printer::set_char_size`]. The previous font is selected again afterwards.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::Font;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.print_row("Coffee", "2.50", Font::FontA)?;
+    /// printer.print_row("Croissant", "1.20", Font::FontA)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_row(&self, left: &str, right: &str, font: Font) -> Result<(), Error> {
+        let previous = self.font.get();
+        self.set_font(font)?;
+        let mut line = text::row(
+            left,
+            right,
+            self.line_columns(font) as usize,
+            self.code_page.get(),
+        );
+        line.push(b'\n');
+        let printed = self.write_raw(line);
+        let restored = self.set_font(previous);
+        printed.and(restored)
+    }
+
+    /// Sets the horizontal tab positions (`ESC D`), in columns from the start of the line, moved to with
+    /// [`Printer::tab`].
+    ///
+    /// At most 32 positions, strictly increasing and not zero, can be set. An empty slice clears them all.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Item, quantity, price
+    /// printer.set_tab_stops(&[20, 28])?;
+    /// printer.write_raw(b"Coffee")?;
+    /// printer.tab()?;
+    /// printer.write_raw(b"2")?;
+    /// printer.tab()?;
+    /// printer.write_raw(b"5.00\n")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_tab_stops(&self, columns: &[u8]) -> Result<(), Error> {
+        if columns.len() > 32 {
+            return Err(Error::PrinterError(format!(
+                "At most 32 tab stops can be set, got {}",
+                columns.len()
+            )));
+        }
+        if columns.first() == Some(&0) || columns.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(Error::PrinterError(
+                "Tab stops must be strictly increasing, and not zero".to_string(),
+            ));
+        }
+        let mut bytes = vec![0x1B, 0x44];
+        bytes.extend_from_slice(columns);
+        bytes.push(0x00);
+        self.write_raw(bytes)
+    }
+
+    /// Moves the print position to the next tab stop (HT), see [`Printer::set_tab_stops`]
+    pub fn tab(&self) -> Result<(), Error> {
+        self.write_raw([0x09])
+    }
+
+    /// Moves the print position to `dots` from the start of the line (`ESC $ nL nH`), in horizontal motion units, a
+    /// dot on most printers. Positions past the profile width are clamped to it.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.write_raw(b"Coffee")?;
+    /// // Prices at the same dot column, whatever the font
+    /// printer.set_absolute_position(400)?;
+    /// printer.write_raw(b"2.50\n")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_absolute_position(&self, dots: u16) -> Result<(), Error> {
+        let [n_l, n_h] = dots.min(self.profile.width()).to_le_bytes();
+        self.write_raw([0x1B, 0x24, n_l, n_h])
+    }
+
+    /// Moves the print position by `dots` from the current one (`ESC \ nL nH`), to the left if negative, in
+    /// horizontal motion units. Moves larger than the profile width are clamped to it.
+    pub fn set_relative_position(&self, dots: i16) -> Result<(), Error> {
+        let width = self.profile.width().min(i16::MAX as u16) as i16;
+        let [n_l, n_h] = dots.clamp(-width, width).to_le_bytes();
+        self.write_raw([0x1B, 0x5C, n_l, n_h])
+    }
+
+    /// Columns in a line with `font`, at the character width set with [`Printer::set_char_size`]
+    fn line_columns(&self, font: Font) -> u8 {
+        self.profile.columns(font) / ((self.char_size.get() >> 4) + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PrinterProfile, VecTransport};
+
+    /// A 32 columns printer writing to memory, with the given code page selected
+    fn printer(code_page: CodePage) -> (Printer, VecTransport) {
+        let sink = VecTransport::new();
+        let mut printer = Printer::with_transport(sink.clone());
+        printer.set_profile(PrinterProfile::builder().width(384).build());
+        printer.set_code_page(code_page).unwrap();
+        sink.take();
+        (printer, sink)
+    }
+
+    #[test]
+    fn section_line_encodes_label() {
+        assert_eq!(
+            section_line("Café", 12, CodePage::PC850),
+            b"--- Caf\x82 ---\n"
+        );
+        // Unknown characters take one column, like the `?` printed
+        assert_eq!(section_line("日本", 8, CodePage::PC437), b"-- ?? --\n");
+        assert_eq!(section_line("", 4, CodePage::PC437), b"----\n");
+        assert_eq!(section_line("LONG LABEL", 6, CodePage::PC437), b" LONG \n");
+    }
+
+    #[test]
+    fn section_at_double_width() {
+        let (printer, sink) = printer(CodePage::PC437);
+        printer.set_char_size(2, 2).unwrap();
+        sink.take();
+        printer.section("ITEMS").unwrap();
+        assert_eq!(sink.take(), b"---- ITEMS -----\n");
+    }
+
+    #[test]
+    fn print_row_aligns_encoded_text() {
+        let (printer, sink) = printer(CodePage::PC858);
+        printer.print_row("Crème", "2,50 €", Font::FontA).unwrap();
+        let bytes = sink.take();
+        // Font selection, the row, then the previous font again
+        assert_eq!(&bytes[..3], [0x1B, 0x4D, 0]);
+        let row = &bytes[3..bytes.len() - 3];
+        assert_eq!(row.len(), 33);
+        assert!(row.starts_with(b"Cr\x8ame "));
+        assert!(row.ends_with(b"2,50 \xd5\n"));
+    }
+
+    #[test]
+    fn print_row_wide_characters_take_one_column() {
+        let (printer, sink) = printer(CodePage::PC437);
+        printer.print_row("日本語", "1.00", Font::FontA).unwrap();
+        let bytes = sink.take();
+        let row = &bytes[3..bytes.len() - 3];
+        assert_eq!(row, format!("???{}1.00\n", " ".repeat(25)).as_bytes());
+    }
+
+    #[test]
+    fn print_row_at_double_width() {
+        let (printer, sink) = printer(CodePage::PC437);
+        printer.set_char_size(2, 1).unwrap();
+        sink.take();
+        printer.print_row("Coffee", "2.50", Font::FontA).unwrap();
+        let bytes = sink.take();
+        assert_eq!(&bytes[3..bytes.len() - 3], b"Coffee      2.50\n");
+    }
+
+    #[test]
+    fn println_justified_uses_code_page() {
+        let (printer, sink) = printer(CodePage::PC850);
+        printer
+            .println_justified("café", Justification::Center)
+            .unwrap();
+        assert_eq!(
+            sink.take(),
+            [0x1B, 0x61, 1, b'c', b'a', b'f', 0x82, b'\n', 0x1B, 0x61, 0]
+        );
+    }
+
+    #[test]
+    fn print_divider_repeats_encoded_character() {
+        let (printer, sink) = printer(CodePage::PC437);
+        // Box drawing horizontal line of PC437
+        printer.print_divider('─', Font::FontB).unwrap();
+        let bytes = sink.take();
+        assert_eq!(&bytes[..3], [0x1B, 0x4D, 1]);
+        assert_eq!(
+            &bytes[3..bytes.len() - 3],
+            [&[0xC4; 42][..], b"\n"].concat()
+        );
+    }
+
+    #[test]
+    fn print_divider_at_double_width() {
+        let (printer, sink) = printer(CodePage::PC437);
+        printer.set_char_size(2, 1).unwrap();
+        sink.take();
+        printer.print_divider('=', Font::FontA).unwrap();
+        let bytes = sink.take();
+        assert_eq!(
+            &bytes[3..bytes.len() - 3],
+            [&[b'='; 16][..], b"\n"].concat()
+        );
+        printer.set_char_size(3, 3).unwrap();
+        sink.take();
+        printer.print_divider('=', Font::FontB).unwrap();
+        let bytes = sink.take();
+        assert_eq!(
+            &bytes[3..bytes.len() - 3],
+            [&[b'='; 14][..], b"\n"].concat()
+        );
+    }
+
+    #[test]
+    fn print_wrapped_at_double_width() {
+        let (printer, sink) = printer(CodePage::PC437);
+        printer.set_char_size(2, 2).unwrap();
+        sink.take();
+        printer
+            .print_wrapped("Organic fair trade coffee beans", Font::FontA)
+            .unwrap();
+        let bytes = sink.take();
+        assert_eq!(
+            &bytes[3..bytes.len() - 3],
+            b"Organic fair\ntrade coffee\nbeans\n"
+        );
+    }
+
+    #[test]
+    fn initialize_resets_code_page() {
+        let (printer, _) = printer(CodePage::PC850);
+        assert_eq!(printer.code_page(), CodePage::PC850);
+        printer.initialize().unwrap();
+        assert_eq!(printer.code_page(), CodePage::PC437);
+    }
+}
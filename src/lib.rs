@@ -4,9 +4,70 @@
 //! - bug fixes
 //! - cleaning unused and unwanted code
 //! - adding read functionality
+//!
+//! ## Cargo features
+//! - `async`: async connection, writes and reads, running the blocking USB calls on tokio's thread pool
+//! - `image`: converting images of the image crate, with dithering, see `EscposImage::from_dynamic_image`
+//! - `log`: debug logs of endpoint detection, interface claiming and transfers (with their byte counts), and
+//!   warnings, through the log crate
+//! - `serde`: serialization of `PrinterProfile`, `PrinterModel` and `CustomModel`, to store them in configuration
+//!   files
+//! - `render-text`: rasterizing text with TrueType fonts, see `EscposImage::from_text`
+//! - `no-op-delay`: removes the 10ms pause made by default after each write (see `Printer::set_op_delay`), for
+//!   hosts where it is handled elsewhere or unaffordable. Some printers lose data without it.
 
+pub use barcode::{barcode_bytes, Barcode, HriPosition};
+pub use batch::Batch;
+pub use codepage::CodePage;
+pub use command::{
+    cut_bytes, cut_with_feed_bytes, feed_dots_bytes, feed_lines_bytes, justification_bytes,
+    CutMode, DrawerPin, Justification,
+};
+pub use device::DeviceInfo;
 pub use error::Error;
-pub use printer::Printer;
+pub use glyph::UserGlyph;
+pub use identity::{IdKind, PrinterType};
+#[cfg(feature = "image")]
+pub use image::Dither;
+pub use image::{BitImageMode, EscposImage, RasterByteOrder};
+pub use model::{CustomModel, PrinterModel};
+pub use network::NetworkTransport;
+pub use printer::{Printer, PrinterBuilder};
+pub use profile::{PrinterProfile, PrinterProfileBuilder};
+pub use qr::{qr_bytes, QrErrorCorrection, QrModel};
+pub use receipt::ReceiptBuilder;
+pub use shared::SharedPrinter;
+pub use status::{
+    AsbFlags, AsbStatus, CutResult, PrinterStatus, ReadyCriteria, StatusEvent, StatusKind,
+    StatusMonitor,
+};
+pub use style::{bold_bytes, underline_bytes, UnderlineMode};
+pub use terminal::TerminalTransport;
+pub use text::{display_columns, Font, FontWidth};
+pub use transport::{debug_hexdump, FileTransport, Transport, VecTransport};
 
+#[cfg(feature = "async")]
+mod asynchronous;
+mod barcode;
+mod batch;
+mod codepage;
+mod command;
+mod device;
 mod error;
+mod glyph;
+mod identity;
+mod image;
+mod layout;
+mod logging;
+mod model;
+mod network;
 mod printer;
+mod profile;
+mod qr;
+mod receipt;
+mod shared;
+mod status;
+mod style;
+mod terminal;
+mod text;
+mod transport;
@@ -0,0 +1,210 @@
+use crate::{Error, Font, Printer, PrinterProfile};
+use std::{collections::HashMap, time::Duration};
+
+/// What is known about a model
+struct ModelData {
+    vendor_id: u16,
+    product_id: u16,
+    /// Bulk write endpoint, detected if unknown
+    write_endpoint: Option<u8>,
+    /// Printable width, in dots
+    width: u16,
+    /// Font A columns
+    font_a_columns: u8,
+}
+
+/// Defines the models and their data, so that the enum, its data and the list of models can't get out of sync
+macro_rules! models {
+    ($($(#[$doc:meta])* $model:ident => $data:expr,)*) => {
+        /// Printers whose USB ids, endpoints and layout are known
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum PrinterModel {
+            $($(#[$doc])* $model,)*
+        }
+
+        impl PrinterModel {
+            /// All the known models, to list the supported printers
+            /// ```rust
+            /// use escpos_rw::PrinterModel;
+            /// for model in PrinterModel::all() {
+            ///     let (vendor_id, product_id) = model.vp_id();
+            ///     println!("{:?}: {:04x}:{:04x}", model, vendor_id, product_id);
+            /// }
+            /// # assert!(PrinterModel::all().contains(&PrinterModel::TMT20));
+            /// ```
+            pub fn all() -> &'static [PrinterModel] {
+                &[$(PrinterModel::$model,)*]
+            }
+
+            fn data(&self) -> ModelData {
+                match self {
+                    $(PrinterModel::$model => $data,)*
+                }
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+models! {
+    /// ZKTeco 58mm printer
+    ZKTeco => ModelData { vendor_id: 0x6868, product_id: 0x0200, write_endpoint: Some(0x02), width: 384, font_a_columns: 32 },
+    /// Epson TM-T20
+    TMT20 => ModelData { vendor_id: 0x04b8, product_id: 0x0e15, write_endpoint: Some(0x01), width: 576, font_a_columns: 48 },
+    /// Epson TM-T88V
+    TMT88V => ModelData { vendor_id: 0x04b8, product_id: 0x0202, write_endpoint: Some(0x01), width: 512, font_a_columns: 42 },
+    /// Bixolon SRP-350
+    SRP350 => ModelData { vendor_id: 0x1504, product_id: 0x0006, write_endpoint: None, width: 512, font_a_columns: 42 },
+    /// Star TSP100
+    TSP100 => ModelData { vendor_id: 0x0519, product_id: 0x0003, write_endpoint: None, width: 576, font_a_columns: 48 },
+}
+
+impl PrinterModel {
+    /// Vendor and product ids
+    pub fn vp_id(&self) -> (u16, u16) {
+        let data = self.data();
+        (data.vendor_id, data.product_id)
+    }
+
+    /// Bulk write endpoint, if known
+    pub fn write_endpoint(&self) -> Option<u8> {
+        self.data().write_endpoint
+    }
+
+    /// USB ids, endpoint if known, paper and layout characteristics
+    /// ```rust
+    /// use escpos_rw::{Font, PrinterModel};
+    /// let profile = PrinterModel::ZKTeco.profile();
+    /// assert_eq!(profile.vp_id(), Some((0x6868, 0x0200)));
+    /// assert_eq!(profile.columns(Font::FontA), 32);
+    /// ```
+    pub fn profile(&self) -> PrinterProfile {
+        let data = self.data();
+        let builder = PrinterProfile::builder()
+            .vp_id(data.vendor_id, data.product_id)
+            .width(data.width)
+            .columns(Font::FontA, data.font_a_columns);
+        match data.write_endpoint {
+            Some(endpoint) => builder.write_endpoint(endpoint),
+            None => builder,
+        }
+        .build()
+    }
+}
+
+/// A model unknown to this crate, defined at runtime.
+///
+/// With the `serde` feature, it can be loaded from a configuration file.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomModel {
+    /// Vendor id
+    pub vendor_id: u16,
+    /// Product id
+    pub product_id: u16,
+    /// Bulk write endpoint, detected if `None`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub write_endpoint: Option<u8>,
+    /// Bulk read endpoint, detected if `None`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub read_endpoint: Option<u8>,
+    /// Time to wait before giving up a read or a write, in milliseconds, 2 seconds if `None`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub timeout_ms: Option<u64>,
+    /// Printable width, in dots
+    pub width: u16,
+    /// Columns per line for each font, when they differ from what the width gives
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub columns_per_font: HashMap<Font, u8>,
+}
+
+impl CustomModel {
+    /// USB ids and settings, paper and layout characteristics
+    pub fn profile(&self) -> PrinterProfile {
+        let mut builder = PrinterProfile::builder()
+            .vp_id(self.vendor_id, self.product_id)
+            .width(self.width);
+        if let Some(endpoint) = self.write_endpoint {
+            builder = builder.write_endpoint(endpoint);
+        }
+        if let Some(endpoint) = self.read_endpoint {
+            builder = builder.read_endpoint(endpoint);
+        }
+        if let Some(timeout) = self.timeout_ms {
+            builder = builder.timeout(Duration::from_millis(timeout));
+        }
+        self.columns_per_font
+            .iter()
+            .fold(builder, |builder, (font, columns)| {
+                builder.columns(*font, *columns)
+            })
+            .build()
+    }
+}
+
+impl Printer {
+    /// Creates a printer of a known model, with its endpoint if known, and its profile
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, Printer, PrinterModel};
+    /// # fn main() -> Result<(), Error> {
+    /// let Some(printer) = Printer::from_model(PrinterModel::TMT20)? else {
+    ///     return Err(escpos_rw::Error::PrinterError(
+    ///         "No printer found !".to_string(),
+    ///     ));
+    /// };
+    /// # Ok(())}
+    /// ```
+    pub fn from_model(model: PrinterModel) -> Result<Option<Printer>, Error> {
+        Printer::open(model.profile())
+    }
+
+    /// Creates a printer of a model defined at runtime, with its endpoints if given, and its profile
+    /// ```rust,no_run
+    /// use escpos_rw::{CustomModel, Error, Font, Printer};
+    /// use std::{collections::HashMap, time::Duration};
+    /// # fn main() -> Result<(), Error> {
+    /// let model = CustomModel {
+    ///     vendor_id: 0x0fe6,
+    ///     product_id: 0x811e,
+    ///     write_endpoint: Some(0x02),
+    ///     read_endpoint: None,
+    ///     timeout_ms: Some(5000),
+    ///     width: 384,
+    ///     columns_per_font: HashMap::from([(Font::FontA, 32), (Font::FontB, 42)]),
+    /// };
+    /// let Some(printer) = Printer::from_custom_model(&model)? else {
+    ///     return Err(escpos_rw::Error::PrinterError(
+    ///         "No printer found !".to_string(),
+    ///     ));
+    /// };
+    /// # Ok(())}
+    /// ```
+    pub fn from_custom_model(model: &CustomModel) -> Result<Option<Printer>, Error> {
+        Printer::open(model.profile())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn models_have_distinct_ids() {
+        let models = PrinterModel::all();
+        assert_eq!(models.len(), 5);
+        for (i, model) in models.iter().enumerate() {
+            assert!(models[i + 1..]
+                .iter()
+                .all(|other| other.vp_id() != model.vp_id()));
+        }
+    }
+
+    #[test]
+    fn model_data() {
+        assert_eq!(PrinterModel::TMT88V.vp_id(), (0x04b8, 0x0202));
+        assert_eq!(PrinterModel::TMT88V.write_endpoint(), Some(0x01));
+        assert_eq!(PrinterModel::SRP350.write_endpoint(), None);
+        assert_eq!(PrinterModel::TSP100.profile().width(), 576);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Error, Printer, Transport};
+use std::{
+    io::{ErrorKind, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+/// Time to wait before giving up connecting, reading or writing
+const NETWORK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Sends the bytes to a network printer, over a raw TCP connection
+pub struct NetworkTransport {
+    stream: TcpStream,
+}
+
+impl NetworkTransport {
+    /// Connects to the printer, trying each address the host resolves to
+    pub fn connect(host: &str, port: u16) -> Result<NetworkTransport, Error> {
+        let mut last_error = None;
+        for address in (host, port).to_socket_addrs()? {
+            match TcpStream::connect_timeout(&address, NETWORK_TIMEOUT) {
+                Ok(stream) => {
+                    stream.set_read_timeout(Some(NETWORK_TIMEOUT))?;
+                    stream.set_write_timeout(Some(NETWORK_TIMEOUT))?;
+                    return Ok(NetworkTransport { stream });
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(match last_error {
+            Some(e) => network_error(e),
+            None => Error::PrinterError(format!("{} has no address", host)),
+        })
+    }
+}
+
+/// Converts an I/O error of the connection, telling timeouts apart
+fn network_error(e: std::io::Error) -> Error {
+    match e.kind() {
+        ErrorKind::TimedOut | ErrorKind::WouldBlock => Error::Timeout,
+        _ => e.into(),
+    }
+}
+
+impl Transport for NetworkTransport {
+    fn write(&self, bytes: &[u8]) -> Result<(), Error> {
+        (&self.stream).write_all(bytes).map_err(network_error)
+    }
+
+    fn read(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        match (&self.stream).read(buffer) {
+            // Reading nothing in a buffer with room means the printer closed the connection
+            Ok(0) if !buffer.is_empty() => {
+                Err(Error::PrinterError("connection closed".to_string()))
+            }
+            read => read.map_err(network_error),
+        }
+    }
+}
+
+impl Printer {
+    /// Connects to a network printer. Most of them listen on port 9100 for raw ESC/POS data.
+    ///
+    /// Connecting, reading and writing time out after 2 seconds.
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// let printer = Printer::open_network("192.168.1.50", 9100)?;
+    /// printer.write_raw(b"Hello\n")?;
+    /// # Ok(())}
+    /// ```
+    pub fn open_network(host: &str, port: u16) -> Result<Printer, Error> {
+        Ok(Printer::with_transport(NetworkTransport::connect(
+            host, port,
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn read_closed_connection() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let transport = NetworkTransport::connect("127.0.0.1", port).unwrap();
+        let (mut stream, _) = listener.accept().unwrap();
+        stream.write_all(&[0x12]).unwrap();
+        drop(stream);
+        let mut buffer = [0; 4];
+        assert_eq!(transport.read(&mut buffer).unwrap(), 1);
+        assert_eq!(buffer[0], 0x12);
+        assert!(matches!(
+            transport.read(&mut buffer),
+            Err(Error::PrinterError(message)) if message == "connection closed"
+        ));
+        assert_eq!(transport.read(&mut []).unwrap(), 0);
+    }
+}
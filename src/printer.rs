@@ -1,18 +1,47 @@
-use crate::Error;
-use rusb::{Context, DeviceHandle, Direction, TransferType, UsbContext};
-use std::{thread, time::Duration};
+use crate::{
+    logging::{log_debug, log_warn},
+    CodePage, Error, Font, Justification, PrinterProfile, RasterByteOrder, Transport,
+};
+use rusb::{ConfigDescriptor, Context, Device, DeviceHandle, Direction, TransferType, UsbContext};
+use std::{
+    cell::Cell,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-const OP_DELAY: u64 = 10;
+/// Pause after each write, by default
+#[cfg(not(feature = "no-op-delay"))]
+const OP_DELAY: Duration = Duration::from_millis(10);
+/// Default height, in dots, of the bands images are split into
+const IMAGE_BAND_HEIGHT: u16 = 128;
+/// Time after which no more input is expected when draining
+const DRAIN_TIMEOUT: Duration = Duration::from_millis(50);
+/// Pause before reading again from a transport that had nothing to read
+const READ_POLL_INTERVAL: Duration = Duration::from_millis(10);
+/// Number of max size packets sent in a single bulk transfer, by default
+const WRITE_CHUNK_PACKETS: usize = 64;
+/// Time to wait before giving up a read or a write, by default
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
 
 struct PrinterConnection {
     /// Bulk write endpoint
     endpoint: u8,
-    /// Bulk read endpoint
-    endpoint_r: u8,
-    /// Device handle
-    dh: DeviceHandle<Context>,
+    /// Bulk read endpoint, if the printer can send data
+    endpoint_r: Option<u8>,
+    /// Claimed interface
+    interface: u8,
+    /// Max packet size of the write endpoint
+    max_packet_size: u16,
+    /// Maximum number of bytes sent in a single bulk transfer
+    write_chunk_size: usize,
+    /// Whether the kernel driver was detached from the interface, to reattach it when done
+    detached_kernel_driver: Cell<bool>,
+    /// Device handle, shared with the transfers running on other threads
+    dh: Arc<DeviceHandle<Context>>,
+    /// Time to wait before giving up reading from the bulk endpoint
+    read_timeout: Duration,
     /// Time to wait before giving up writing to the bulk endpoint
-    timeout: std::time::Duration,
+    write_timeout: Duration,
 }
 
 struct UsbConnectionData {
@@ -24,17 +53,498 @@ struct UsbConnectionData {
     pub endpoint_w: Option<u8>,
     /// Endpoint where the usb data is meant to be read from
     pub endpoint_r: Option<u8>,
+    /// Interface of the printer function
+    pub interface: u8,
+    /// Serial number of the printer, when several have the same vid and pid
+    pub serial: Option<String>,
+    /// Maximum number of bytes sent in a single bulk transfer, derived from the endpoint if not set
+    pub write_chunk_size: Option<usize>,
+    /// Claim the interface even if the kernel driver state can't be found out
+    pub ignore_kernel_driver: bool,
+    /// Detach the kernel driver bound to the interface, if any
+    pub detach_kernel_driver: bool,
+    /// Configuration selected before claiming the interface, the active one if not set
+    pub configuration: Option<u8>,
+    /// Timeout for bulk read operations
+    pub read_timeout: Duration,
     /// Timeout for bulk write operations
-    pub timeout: std::time::Duration,
+    pub write_timeout: Duration,
+}
+
+/// Where the bytes sent to the printer go
+enum Connection {
+    /// A USB printer, with the parameters it was opened with, to reopen it
+    Usb(PrinterConnection, UsbConnectionData),
+    /// Any other transport
+    Transport(Box<dyn Transport>),
 }
 
 /// The printer object represents the thermal printer.
 pub struct Printer {
     /// Actual connection to the printer
-    printer_connection: PrinterConnection,
+    connection: Connection,
+    /// Byte order of the raster image dimensions
+    pub(crate) raster_byte_order: RasterByteOrder,
+    /// Maximum number of rows sent in a single raster image command
+    pub(crate) image_band_height: u16,
+    /// Paper and layout characteristics
+    pub(crate) profile: PrinterProfile,
+    /// Current justification
+    pub(crate) justification: Cell<Justification>,
+    /// Current font
+    pub(crate) font: Cell<Font>,
+    /// Current code page, text is encoded with
+    pub(crate) code_page: Cell<CodePage>,
+    /// Current character magnification, as sent with `GS !`
+    pub(crate) char_size: Cell<u8>,
+    /// Number of times a transfer failing with a transient error is retried
+    retries: u32,
+    /// Delay before the first retry, doubled for each following one
+    retry_backoff: Duration,
+    /// Pause after each write
+    #[cfg(not(feature = "no-op-delay"))]
+    op_delay: Duration,
+}
+
+impl UsbConnectionData {
+    /// Looks for the printer and opens it.
+    ///
+    /// Devices that can't be inspected are skipped, so that an unrelated misbehaving device doesn't prevent finding
+    /// the printer. If no printer could be opened, the error of the last matching device that couldn't be is
+    /// returned.
+    fn open(&self) -> Result<Option<PrinterConnection>, Error> {
+        let context = usb_context()?;
+
+        let devices = context.devices().map_err(Error::UsbError)?;
+        let mut skipped_error = None;
+        for device in devices.iter() {
+            let Ok(s) = device.device_descriptor() else {
+                continue;
+            };
+            if s.vendor_id() == self.vendor_id && s.product_id() == self.product_id {
+                // Before opening the device, we must find the bulk endpoint
+                let config_descriptor = match self.configuration {
+                    Some(configuration) => {
+                        config_descriptor(&device, s.num_configurations(), configuration)
+                    }
+                    None => device.active_config_descriptor(),
+                };
+                let config_descriptor = match config_descriptor {
+                    Ok(config_descriptor) => config_descriptor,
+                    Err(e) => {
+                        skipped_error = Some(Error::UsbError(e));
+                        continue;
+                    }
+                };
+                let write_endpoint = self.endpoint_w.or_else(|| {
+                    bulk_endpoint(&config_descriptor, Some(self.interface), Direction::Out)
+                });
+                let Some(actual_endpoint) = write_endpoint else {
+                    skipped_error = Some(Error::NoBulkEndpoint);
+                    continue;
+                };
+                // Write-only printers have no read endpoint
+                let actual_endpoint_r = self.endpoint_r.or_else(|| {
+                    bulk_endpoint(&config_descriptor, Some(self.interface), Direction::In)
+                });
+
+                let max_packet_size = max_packet_size(&config_descriptor, actual_endpoint)
+                    .filter(|size| *size > 0)
+                    .unwrap_or(64);
+                let write_chunk_size = self
+                    .write_chunk_size
+                    .unwrap_or(max_packet_size as usize * WRITE_CHUNK_PACKETS);
+                log_debug!(
+                    "Printer {:04x}:{:04x}: write endpoint {:#04x}, read endpoint {:?}, max packet size {}",
+                    self.vendor_id, self.product_id, actual_endpoint, actual_endpoint_r, max_packet_size
+                );
+
+                // Now we continue opening the device
+
+                match device.open() {
+                    Ok(dh) => {
+                        if let Some(serial) = &self.serial {
+                            let actual_serial = dh.read_serial_number_string_ascii(&s).ok();
+                            if actual_serial.as_ref() != Some(serial) {
+                                continue;
+                            }
+                        }
+                        // The printer is found: failing to configure or claim it is fatal
+                        if let Some(configuration) = self.configuration {
+                            select_configuration(&dh, configuration)?;
+                        }
+                        let detached_kernel_driver = claim(&dh, self)?;
+                        return Ok(Some(PrinterConnection {
+                            endpoint: actual_endpoint,
+                            endpoint_r: actual_endpoint_r,
+                            interface: self.interface,
+                            max_packet_size,
+                            write_chunk_size,
+                            detached_kernel_driver: Cell::new(detached_kernel_driver),
+                            dh: Arc::new(dh),
+                            read_timeout: self.read_timeout,
+                            write_timeout: self.write_timeout,
+                        }));
+                    }
+                    Err(e) => skipped_error = Some(Error::UsbError(e)),
+                };
+            }
+        }
+        match skipped_error {
+            Some(e) => Err(e),
+            // No printer was found with such vid and pid (and serial)
+            None => Ok(None),
+        }
+    }
+}
+
+impl PrinterConnection {
+    /// Releases the interface, and gives it back to the kernel driver if it was detached.
+    /// Errors are ignored: the device may already be gone.
+    fn release(&mut self) {
+        let _ = self.dh.release_interface(self.interface);
+        if self.detached_kernel_driver.replace(false) {
+            let _ = self.dh.attach_kernel_driver(self.interface);
+        }
+    }
+}
+
+/// Finds a bulk endpoint of the given direction, on the given interface or any of them
+pub(crate) fn bulk_endpoint(
+    config_descriptor: &ConfigDescriptor,
+    interface: Option<u8>,
+    direction: Direction,
+) -> Option<u8> {
+    let mut detected_endpoint: Option<u8> = None;
+    // Horrible to have 3 nested for, but so be it
+    for interface in config_descriptor
+        .interfaces()
+        .filter(|i| interface.is_none_or(|number| i.number() == number))
+    {
+        for descriptor in interface.descriptors() {
+            for endpoint in descriptor.endpoint_descriptors() {
+                if endpoint.transfer_type() == TransferType::Bulk
+                    && endpoint.direction() == direction
+                {
+                    detected_endpoint = Some(endpoint.address());
+                }
+            }
+        }
+    }
+    detected_endpoint
+}
+
+/// Max packet size (wMaxPacketSize) of the endpoint with the given address
+fn max_packet_size(config_descriptor: &ConfigDescriptor, address: u8) -> Option<u16> {
+    config_descriptor
+        .interfaces()
+        .flat_map(|interface| interface.descriptors())
+        .flat_map(|descriptor| {
+            descriptor
+                .endpoint_descriptors()
+                .map(|endpoint| (endpoint.address(), endpoint.max_packet_size()))
+                .collect::<Vec<_>>()
+        })
+        .find(|(endpoint, _)| *endpoint == address)
+        .map(|(_, size)| size)
+}
+
+/// Descriptor of the configuration with the given value (bConfigurationValue), among the device's `count` ones
+fn config_descriptor(
+    device: &Device<Context>,
+    count: u8,
+    configuration: u8,
+) -> Result<ConfigDescriptor, rusb::Error> {
+    (0..count)
+        .filter_map(|index| device.config_descriptor(index).ok())
+        .find(|descriptor| descriptor.number() == configuration)
+        .ok_or(rusb::Error::NotFound)
+}
+
+/// Makes the configuration active, unless it already is: setting it again would reset the device on some systems
+fn select_configuration(dh: &DeviceHandle<Context>, configuration: u8) -> Result<(), Error> {
+    if dh.active_configuration().ok() == Some(configuration) {
+        return Ok(());
+    }
+    log_debug!("Selecting configuration {}", configuration);
+    dh.set_active_configuration(configuration)
+        .map_err(Error::UsbError)
+}
+
+/// Transfers a write of `len` bytes is made of: the length of each chunk, at most `chunk_size` bytes, and whether it
+/// is followed by a zero-length packet.
+///
+/// A transfer whose length is a multiple of the max packet size can't be told apart from one still going on, so
+/// such chunks are ended with a zero-length packet. Nothing is sent for an empty write.
+fn write_plan(len: usize, chunk_size: usize, max_packet_size: u16) -> Vec<(usize, bool)> {
+    let chunk_size = chunk_size.max(1);
+    let max_packet_size = (max_packet_size as usize).max(1);
+    (0..len)
+        .step_by(chunk_size)
+        .map(|start| {
+            let chunk = chunk_size.min(len - start);
+            (chunk, chunk.is_multiple_of(max_packet_size))
+        })
+        .collect()
+}
+
+/// Sends bytes as planned by [`write_plan`] with `send`, which returns how many bytes of a chunk were accepted,
+/// calling `pause` after each chunk.
+///
+/// Returns the number of bytes accepted, or [`Error::ShortWrite`] if a chunk wasn't accepted in full.
+fn write_chunks(
+    bytes: &[u8],
+    chunk_size: usize,
+    max_packet_size: u16,
+    mut send: impl FnMut(&[u8]) -> Result<usize, Error>,
+    mut pause: impl FnMut(),
+) -> Result<usize, Error> {
+    let mut written = 0;
+    for (chunk_len, zero_length_packet) in write_plan(bytes.len(), chunk_size, max_packet_size) {
+        let chunk = &bytes[written..written + chunk_len];
+        let chunk_written = send(chunk)?;
+        written += chunk_written;
+        if chunk_written != chunk_len {
+            pause();
+            return Err(Error::ShortWrite {
+                written,
+                expected: bytes.len(),
+            });
+        }
+        if zero_length_packet {
+            send(&[])?;
+        }
+        pause();
+    }
+    Ok(written)
+}
+
+/// Time left before `deadline`, `None` once it is over.
+///
+/// USB timeouts are whole milliseconds, and a 0ms one waits forever: less than a millisecond left is over too.
+fn remaining_time(deadline: Duration, elapsed: Duration) -> Option<Duration> {
+    deadline
+        .checked_sub(elapsed)
+        .filter(|remaining| *remaining >= Duration::from_millis(1))
+}
+
+/// Error of the USB specific methods, for printers using another transport
+fn not_usb() -> Error {
+    Error::PrinterError("Not a USB printer".to_string())
+}
+
+/// The read endpoint, or an error for write-only printers
+fn read_endpoint(endpoint_r: Option<u8>) -> Result<u8, Error> {
+    endpoint_r.ok_or_else(|| Error::PrinterError("no read endpoint".to_string()))
+}
+
+/// Creates the libusb context, explaining the most likely cause when it fails
+pub(crate) fn usb_context() -> Result<Context, Error> {
+    Context::new().map_err(|e| {
+        Error::PrinterError(format!(
+            "USB is unavailable on this system (no USB subsystem in a container or CI ?), \
+             a non-USB backend is needed: {}",
+            e
+        ))
+    })
+}
+
+/// Detaches the kernel driver if needed and enabled, and claims the printer interface. Returns true if the kernel
+/// driver was detached.
+///
+/// Failing to find out if the kernel driver is active is an error, unless `ignore_kernel_driver` is set: claiming
+/// is then attempted anyway. Platforms without kernel drivers to detach are not concerned.
+fn claim(dh: &DeviceHandle<Context>, connection_data: &UsbConnectionData) -> Result<bool, Error> {
+    let interface = connection_data.interface;
+    let active = if !connection_data.detach_kernel_driver {
+        false
+    } else {
+        match dh.kernel_driver_active(interface) {
+            Ok(active) => active,
+            Err(rusb::Error::NotSupported) => false,
+            Err(e) if connection_data.ignore_kernel_driver => {
+                log_warn!(
+                    "Could not find out if the kernel driver of interface {} is active ({}), claiming it anyway",
+                    interface, e
+                );
+                false
+            }
+            Err(e) => {
+                return Err(Error::PrinterError(format!(
+                    "Could not find out if the kernel driver is active ({}), \
+                     PrinterBuilder::ignore_kernel_driver claims the printer anyway",
+                    e
+                )))
+            }
+        }
+    };
+    let mut detached = false;
+    if active {
+        // The kernel is active, we have to detach it
+        log_debug!("Detaching the kernel driver of interface {}", interface);
+        match dh.detach_kernel_driver(interface) {
+            Ok(_) => detached = true,
+            Err(e) => return Err(Error::UsbError(e)),
+        };
+    }
+    // Now we claim the interface
+    match dh.claim_interface(interface) {
+        Ok(_) => log_debug!("Claimed interface {}", interface),
+        Err(e) => return Err(Error::UsbError(e)),
+    }
+    Ok(detached)
+}
+
+/// Opens a printer with explicit connection parameters, see [`Printer::builder`]
+pub struct PrinterBuilder {
+    connection_data: UsbConnectionData,
+    /// Initialize the printer once opened
+    reset_on_connect: bool,
+    /// Paper and layout characteristics
+    profile: PrinterProfile,
+}
+
+impl PrinterBuilder {
+    /// Sets the bulk endpoint written to, instead of detecting it
+    pub fn write_endpoint(mut self, endpoint: u8) -> Self {
+        self.connection_data.endpoint_w = Some(endpoint);
+        self
+    }
+
+    /// Sets the bulk endpoint read from, instead of detecting it
+    pub fn read_endpoint(mut self, endpoint: u8) -> Self {
+        self.connection_data.endpoint_r = Some(endpoint);
+        self
+    }
+
+    /// Sets the interface of the printer function (0 by default), for composite devices
+    pub fn interface(mut self, interface: u8) -> Self {
+        self.connection_data.interface = interface;
+        self
+    }
+
+    /// Only opens the printer with the given serial number (iSerialNumber string descriptor)
+    pub fn serial(mut self, serial: &str) -> Self {
+        self.connection_data.serial = Some(serial.to_string());
+        self
+    }
+
+    /// Sets the time to wait before giving up a read or a write (2 seconds by default)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.connection_data.read_timeout = timeout;
+        self.connection_data.write_timeout = timeout;
+        self
+    }
+
+    /// Sets the time to wait before giving up a read (2 seconds by default), see [`Printer::set_read_timeout`]
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.connection_data.read_timeout = timeout;
+        self
+    }
+
+    /// Sets the time to wait before giving up a write (2 seconds by default), see [`Printer::set_write_timeout`]
+    pub fn write_timeout(mut self, timeout: Duration) -> Self {
+        self.connection_data.write_timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum number of bytes sent in a single bulk transfer, see [`Printer::set_write_chunk_size`]
+    pub fn write_chunk_size(mut self, size: usize) -> Self {
+        self.connection_data.write_chunk_size = Some(size.max(1));
+        self
+    }
+
+    /// Claims the printer interface even if it can't be found out whether a kernel driver is bound to it, instead of
+    /// failing to open the printer. Claiming may then fail if a kernel driver is actually bound.
+    pub fn ignore_kernel_driver(mut self, ignore: bool) -> Self {
+        self.connection_data.ignore_kernel_driver = ignore;
+        self
+    }
+
+    /// Detaches the kernel driver bound to the printer interface before claiming it (on Linux by default). Elsewhere,
+    /// like on Windows with the WinUSB driver, there is no kernel driver to detach, and the check is skipped.
+    pub fn detach_kernel_driver(mut self, detach: bool) -> Self {
+        self.connection_data.detach_kernel_driver = detach;
+        self
+    }
+
+    /// Selects the configuration (its bConfigurationValue) before claiming the interface, for printers exposing
+    /// their bulk endpoints only in a configuration which isn't the active one. The endpoints are looked for in it.
+    ///
+    /// The configuration can't be changed while another program or a kernel driver has claimed an interface of the
+    /// device: opening then fails with [`rusb::Error::Busy`].
+    pub fn configuration(mut self, configuration: u8) -> Self {
+        self.connection_data.configuration = Some(configuration);
+        self
+    }
+
+    /// Sets the profile used for layout, see [`Printer::set_profile`]
+    pub fn profile(mut self, profile: PrinterProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Initializes the printer once opened (see [`Printer::initialize`]), so that it doesn't keep the settings of a
+    /// previous job
+    pub fn reset_on_connect(mut self, reset: bool) -> Self {
+        self.reset_on_connect = reset;
+        self
+    }
+
+    /// Looks for the printer and opens it. Returns `None` if no printer has the given VID/PID (and serial number).
+    pub fn open(self) -> Result<Option<Printer>, Error> {
+        let Some(printer_connection) = self.connection_data.open()? else {
+            return Ok(None);
+        };
+        let printer = Printer::from_connection(
+            Connection::Usb(printer_connection, self.connection_data),
+            self.profile,
+        );
+        if self.reset_on_connect {
+            printer.initialize()?;
+        }
+        Ok(Some(printer))
+    }
 }
 
 impl Printer {
+    fn from_connection(connection: Connection, profile: PrinterProfile) -> Printer {
+        Printer {
+            connection,
+            raster_byte_order: RasterByteOrder::default(),
+            image_band_height: IMAGE_BAND_HEIGHT,
+            profile,
+            justification: Cell::new(Justification::default()),
+            font: Cell::new(Font::default()),
+            code_page: Cell::new(CodePage::default()),
+            char_size: Cell::new(0),
+            retries: 0,
+            retry_backoff: Duration::ZERO,
+            #[cfg(not(feature = "no-op-delay"))]
+            op_delay: OP_DELAY,
+        }
+    }
+
+    /// Creates a printer sending its bytes to the given transport instead of a USB device, with the default
+    /// profile (see [`Printer::set_profile`]).
+    ///
+    /// USB specific methods, like [`Printer::reset`], fail with such a printer.
+    /// ```rust
+    /// use escpos_rw::{Error, Printer, VecTransport};
+    /// # fn main() -> Result<(), Error> {
+    /// let sink = VecTransport::new();
+    /// let printer = Printer::with_transport(sink.clone());
+    /// printer.set_bold(true)?;
+    /// assert_eq!(sink.bytes(), [0x1B, 0x45, 1]);
+    /// # Ok(())}
+    /// ```
+    pub fn with_transport<T: Transport + 'static>(transport: T) -> Printer {
+        Printer::from_connection(
+            Connection::Transport(Box::new(transport)),
+            PrinterProfile::default(),
+        )
+    }
+
     /// Creates the printer with the given VID/PID
     /// ```rust,no_run
     /// use escpos_rw::{Error, Printer};
@@ -47,111 +557,233 @@ impl Printer {
     /// # Ok(())}
     /// ```
     pub fn new(vendor_id: u16, product_id: u16) -> Result<Option<Printer>, Error> {
-        let printer_connection_data = UsbConnectionData {
+        Printer::builder(vendor_id, product_id).open()
+    }
+
+    /// Creates the printer with the given VID/PID, like [`Printer::new`], failing with [`Error::DeviceNotFound`] if
+    /// no device has them.
+    ///
+    /// This tells "no printer plugged in" apart from other errors, like a printer used by another program.
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// match Printer::connect(0x04b8, 0x0202) {
+    ///     Ok(printer) => printer.write_raw(b"Hello\n")?,
+    ///     Err(Error::DeviceNotFound { .. }) => eprintln!("Please plug the printer in"),
+    ///     Err(e) => eprintln!("The printer can't be used: {}", e),
+    /// }
+    /// # Ok(())}
+    /// ```
+    pub fn connect(vendor_id: u16, product_id: u16) -> Result<Printer, Error> {
+        Printer::new(vendor_id, product_id)?.ok_or(Error::DeviceNotFound {
             vendor_id,
             product_id,
-            endpoint_w: None,
-            endpoint_r: None,
-            timeout: std::time::Duration::from_secs(2),
-        };
-
-        // Quick check for the profile containing at least one font
-        let context = Context::new().map_err(Error::UsbError)?;
+        })
+    }
 
-        let devices = context.devices().map_err(Error::UsbError)?;
-        for device in devices.iter() {
-            let s = device.device_descriptor().map_err(Error::UsbError)?;
-            if s.vendor_id() == printer_connection_data.vendor_id
-                && s.product_id() == printer_connection_data.product_id
-            {
-                // Before opening the device, we must find the bulk endpoint
-                let config_descriptor =
-                    device.active_config_descriptor().map_err(Error::UsbError)?;
-                let actual_endpoint = if let Some(endpoint_w) = printer_connection_data.endpoint_w {
-                    endpoint_w
-                } else {
-                    let mut detected_endpoint: Option<u8> = None;
-                    // Horrible to have 3 nested for, but so be it
-                    for interface in config_descriptor.interfaces() {
-                        for descriptor in interface.descriptors() {
-                            for endpoint in descriptor.endpoint_descriptors() {
-                                if let (TransferType::Bulk, Direction::Out) =
-                                    (endpoint.transfer_type(), endpoint.direction())
-                                {
-                                    detected_endpoint = Some(endpoint.address());
-                                }
-                            }
-                        }
-                    }
+    /// Creates the printer described by the profile: its VID/PID, endpoints and timeout are used to open it, its
+    /// width and columns to lay out text.
+    ///
+    /// Fails if the profile has no VID/PID. Other settings are available with [`Printer::builder`].
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, Font, Printer, PrinterProfile};
+    /// use std::time::Duration;
+    /// # fn main() -> Result<(), Error> {
+    /// let profile = PrinterProfile::builder()
+    ///     .vp_id(0x6868, 0x0200)
+    ///     .write_endpoint(0x02)
+    ///     .timeout(Duration::from_secs(5))
+    ///     .width(384)
+    ///     .columns(Font::FontB, 42)
+    ///     .build();
+    /// let Some(printer) = Printer::open(profile)? else {
+    ///     return Err(escpos_rw::Error::PrinterError(
+    ///         "No printer found !".to_string(),
+    ///     ));
+    /// };
+    /// # Ok(())}
+    /// ```
+    pub fn open(profile: PrinterProfile) -> Result<Option<Printer>, Error> {
+        let Some((vendor_id, product_id)) = profile.vp_id() else {
+            return Err(Error::PrinterError(
+                "The profile has no vendor and product ids".to_string(),
+            ));
+        };
+        let mut builder = Printer::builder(vendor_id, product_id);
+        if let Some(endpoint) = profile.write_endpoint() {
+            builder = builder.write_endpoint(endpoint);
+        }
+        if let Some(endpoint) = profile.read_endpoint() {
+            builder = builder.read_endpoint(endpoint);
+        }
+        if let Some(timeout) = profile.timeout() {
+            builder = builder.timeout(timeout);
+        }
+        builder.profile(profile).open()
+    }
 
-                    if let Some(detected_endpoint) = detected_endpoint {
-                        detected_endpoint
-                    } else {
-                        return Err(Error::NoBulkEndpoint);
-                    }
-                };
+    /// Creates the printer with the given VID/PID and serial number, to choose among identical printers.
+    ///
+    /// Returns `None` if no printer has this serial number.
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// let Some(printer) = Printer::open_by_serial(0x04b8, 0x0e15, "X5G1234567")? else {
+    ///     return Err(escpos_rw::Error::PrinterError(
+    ///         "No printer found !".to_string(),
+    ///     ));
+    /// };
+    /// # Ok(())}
+    /// ```
+    pub fn open_by_serial(
+        vendor_id: u16,
+        product_id: u16,
+        serial: &str,
+    ) -> Result<Option<Printer>, Error> {
+        Printer::builder(vendor_id, product_id)
+            .serial(serial)
+            .open()
+    }
 
-                let actual_endpoint_r = if let Some(endpoint_r) = printer_connection_data.endpoint_r
-                {
-                    endpoint_r
-                } else {
-                    let mut detected_endpoint_r: Option<u8> = None;
-                    // Horrible to have 3 nested for, but so be it
-                    for interface in config_descriptor.interfaces() {
-                        for descriptor in interface.descriptors() {
-                            for endpoint in descriptor.endpoint_descriptors() {
-                                if let (TransferType::Bulk, Direction::In) =
-                                    (endpoint.transfer_type(), endpoint.direction())
-                                {
-                                    detected_endpoint_r = Some(endpoint.address());
-                                }
-                            }
-                        }
-                    }
+    /// Creates a builder to open the printer with the given VID/PID, with explicit endpoints, interface or timeout.
+    ///
+    /// Unset endpoints are detected among the bulk endpoints of the interface.
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, Printer};
+    /// use std::time::Duration;
+    /// # fn main() -> Result<(), Error> {
+    /// // A composite device, with the printer on interface 1
+    /// let Some(printer) = Printer::builder(0x04b8, 0x0202)
+    ///     .interface(1)
+    ///     .write_endpoint(0x02)
+    ///     .timeout(Duration::from_secs(5))
+    ///     .open()?
+    /// else {
+    ///     return Err(escpos_rw::Error::PrinterError(
+    ///         "No printer found !".to_string(),
+    ///     ));
+    /// };
+    /// # Ok(())}
+    /// ```
+    pub fn builder(vendor_id: u16, product_id: u16) -> PrinterBuilder {
+        PrinterBuilder {
+            connection_data: UsbConnectionData {
+                vendor_id,
+                product_id,
+                endpoint_w: None,
+                endpoint_r: None,
+                interface: 0,
+                serial: None,
+                write_chunk_size: None,
+                ignore_kernel_driver: false,
+                detach_kernel_driver: cfg!(target_os = "linux"),
+                configuration: None,
+                read_timeout: DEFAULT_TIMEOUT,
+                write_timeout: DEFAULT_TIMEOUT,
+            },
+            reset_on_connect: false,
+            profile: PrinterProfile::default(),
+        }
+    }
 
-                    if let Some(detected_endpoint_r) = detected_endpoint_r {
-                        detected_endpoint_r
-                    } else {
-                        return Err(Error::NoBulkEndpoint);
-                    }
-                };
+    /// Resets the USB device, which is faster than closing and reopening it.
+    ///
+    /// On some platforms this invalidates the interface claim: [`Printer::reset`] resets the device and claims the
+    /// interface again.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.reset_device()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reset_device(&self) -> Result<(), Error> {
+        self.usb()?.dh.reset().map_err(Error::UsbError)
+    }
 
-                // Now we continue opening the device
+    /// Resets the USB device and claims the interface again, to recover a printer that stopped responding without
+    /// unplugging it.
+    ///
+    /// If the claim fails, the device may have been enumerated again by the reset: [`Printer::reconnect`] reopens it.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// if printer.write_raw(b"Hello\n").is_err() {
+    ///     printer.reset()?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reset(&self) -> Result<(), Error> {
+        self.reset_device()?;
+        let Connection::Usb(connection, connection_data) = &self.connection else {
+            return Err(not_usb());
+        };
+        let detached = claim(&connection.dh, connection_data)?;
+        if detached {
+            connection.detached_kernel_driver.set(true);
+        }
+        Ok(())
+    }
 
-                match device.open() {
-                    Ok(dh) => {
-                        if let Ok(active) = dh.kernel_driver_active(0) {
-                            if active {
-                                // The kernel is active, we have to detach it
-                                match dh.detach_kernel_driver(0) {
-                                    Ok(_) => (),
-                                    Err(e) => return Err(Error::UsbError(e)),
-                                };
-                            }
-                        } else {
-                            println!("Could not find out if kernel driver is active, might encounter a problem soon.");
-                        };
-                        // Now we claim the interface
-                        match dh.claim_interface(0) {
-                            Ok(_) => (),
-                            Err(e) => return Err(Error::UsbError(e)),
-                        }
-                        let timeout = printer_connection_data.timeout;
-                        return Ok(Some(Printer {
-                            printer_connection: PrinterConnection {
-                                endpoint: actual_endpoint,
-                                endpoint_r: actual_endpoint_r,
-                                dh,
-                                timeout,
-                            },
-                        }));
-                    }
-                    Err(e) => return Err(Error::UsbError(e)),
-                };
+    /// Reconnects to the printer after a transient error.
+    ///
+    /// With `reuse_handle`, the current device handle is first reset and its interface claimed again, which is
+    /// quicker and less disruptive. If this fails, or without `reuse_handle`, the device is looked for again and
+    /// reopened.
+    ///
+    /// The current handle is only replaced once the device has been reopened. Otherwise, it claims its interface
+    /// back: the printer is left as it was, and reconnecting can be tried again.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(mut printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// if printer.write_raw(b"Hello\n").is_err() {
+    ///     printer.reconnect(true)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reconnect(&mut self, reuse_handle: bool) -> Result<(), Error> {
+        if reuse_handle && self.reset().is_ok() {
+            return Ok(());
+        }
+        let Connection::Usb(connection, connection_data) = &mut self.connection else {
+            return Err(not_usb());
+        };
+        // The interface can't be claimed by the new handle while the current one holds it
+        connection.release();
+        let error = match connection_data.open() {
+            Ok(Some(printer_connection)) => {
+                *connection = printer_connection;
+                return Ok(());
             }
+            Ok(None) => Error::DeviceNotFound {
+                vendor_id: connection_data.vendor_id,
+                product_id: connection_data.product_id,
+            },
+            Err(e) => e,
+        };
+        // Not reopened: back to the current handle, which may still work if the device wasn't unplugged
+        match claim(&connection.dh, connection_data) {
+            Ok(detached) => connection.detached_kernel_driver.set(detached),
+            Err(e) => log_warn!("Could not claim the interface back: {}", e),
         }
-        // No printer was found with such vid and pid
-        Ok(None)
+        Err(error)
     }
 
     /// Sends bytes to the printer
@@ -170,21 +802,196 @@ impl Printer {
     /// # }
     /// ```
     pub fn write_raw<A: AsRef<[u8]>>(&self, bytes: A) -> Result<(), Error> {
-        let PrinterConnection {
-                endpoint,
-                endpoint_r: _,
-                dh,
-                timeout,
-            } = &self.printer_connection;
-        {
-            dh.write_bulk(*endpoint, bytes.as_ref(), *timeout)
-                .map_err(Error::UsbError)?;
-            thread::sleep(Duration::from_millis(OP_DELAY));
-            Ok(())
+        self.write_raw_timeout(bytes, self.write_timeout())
+    }
+
+    /// Sends bytes to the printer like [`Printer::write_raw`], returning how many the printer reported accepting.
+    ///
+    /// If it accepted only part of them, [`Error::ShortWrite`] tells how many it did. Other transports write all the
+    /// bytes or fail.
+    /// ```rust
+    /// use escpos_rw::{Error, Printer, VecTransport};
+    /// # fn main() -> Result<(), Error> {
+    /// let printer = Printer::with_transport(VecTransport::new());
+    /// assert_eq!(printer.write_raw_counted(b"Hello\n")?, 6);
+    /// # Ok(())}
+    /// ```
+    pub fn write_raw_counted<A: AsRef<[u8]>>(&self, bytes: A) -> Result<usize, Error> {
+        let bytes = bytes.as_ref();
+        match &self.connection {
+            Connection::Usb(..) => self.transfer(self.write_timeout())?.write_counted(bytes),
+            Connection::Transport(transport) => {
+                log_debug!("Writing {} bytes to the transport", bytes.len());
+                // Transports write all the bytes, or fail
+                transport.write(bytes)?;
+                Ok(bytes.len())
+            }
+        }
+    }
+
+    /// Sends bytes to the printer, waiting at most `timeout` instead of the printer's write timeout.
+    ///
+    /// Large payloads are split into chunks of [`Printer::write_chunk_size`] bytes, each sent with its own transfer
+    /// and followed by the write delay. The timeout applies to each chunk. [`Error::ShortWrite`] is returned if the
+    /// printer didn't accept all the bytes. Chunks whose length is a multiple of [`Printer::max_packet_size`] are
+    /// followed by a zero-length packet, see [`Printer::flush`].
+    ///
+    /// Other transports are written to at once, and handle timeouts themselves.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use std::time::Duration;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// # let large_image = Vec::new();
+    /// printer.write_raw_timeout(large_image, Duration::from_secs(10))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_raw_timeout<A: AsRef<[u8]>>(
+        &self,
+        bytes: A,
+        timeout: Duration,
+    ) -> Result<(), Error> {
+        match &self.connection {
+            Connection::Usb(..) => self.transfer(timeout)?.write(bytes.as_ref()),
+            Connection::Transport(transport) => {
+                log_debug!("Writing {} bytes to the transport", bytes.as_ref().len());
+                transport.write(bytes.as_ref())
+            }
         }
     }
 
-    /// Reads bytes from the printer
+    /// Sends a zero-length packet to a USB printer, ending the current transfer. Does nothing with other transports.
+    ///
+    /// A transfer whose length is a multiple of the endpoint's max packet size can't be told apart from one still
+    /// going on, and some USB bridges wait for more data until such a packet comes. [`Printer::write_raw`] already
+    /// sends one after each of these transfers.
+    pub fn flush(&self) -> Result<(), Error> {
+        match &self.connection {
+            Connection::Usb(..) => self.transfer(self.write_timeout())?.flush(),
+            Connection::Transport(_) => Ok(()),
+        }
+    }
+
+    /// Max packet size (wMaxPacketSize) of the write endpoint, to align buffers on, 64 if the endpoint doesn't tell.
+    /// `None` if the printer isn't a USB one.
+    pub fn max_packet_size(&self) -> Option<u16> {
+        self.usb().ok().map(|connection| connection.max_packet_size)
+    }
+
+    /// Maximum number of bytes sent in a single bulk transfer, `None` if the printer isn't a USB one.
+    ///
+    /// By default, 64 times [`Printer::max_packet_size`].
+    pub fn write_chunk_size(&self) -> Option<usize> {
+        self.usb()
+            .ok()
+            .map(|connection| connection.write_chunk_size)
+    }
+
+    /// Sets the maximum number of bytes sent in a single bulk transfer, for controllers failing on large transfers.
+    /// It is kept when reconnecting. Ignored if the printer isn't a USB one.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(mut printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Send images one 64 bytes packet at a time
+    /// printer.set_write_chunk_size(64);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_write_chunk_size(&mut self, size: usize) {
+        if let Connection::Usb(connection, connection_data) = &mut self.connection {
+            let size = size.max(1);
+            connection.write_chunk_size = size;
+            connection_data.write_chunk_size = Some(size);
+        }
+    }
+
+    /// Time to wait before giving up a write (2 seconds by default), see [`Printer::read_timeout`] for reads
+    pub fn timeout(&self) -> Duration {
+        self.write_timeout()
+    }
+
+    /// Time to wait before giving up a read (2 seconds by default)
+    pub fn read_timeout(&self) -> Duration {
+        match &self.connection {
+            Connection::Usb(connection, _) => connection.read_timeout,
+            Connection::Transport(_) => DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Time to wait before giving up a write (2 seconds by default)
+    pub fn write_timeout(&self) -> Duration {
+        match &self.connection {
+            Connection::Usb(connection, _) => connection.write_timeout,
+            Connection::Transport(_) => DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Sets the time to wait before giving up a read or a write. It is kept when reconnecting.
+    ///
+    /// Ignored if the printer isn't a USB one: other transports handle timeouts themselves.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use std::time::Duration;
+    /// # let Some(mut printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_timeout(Duration::from_secs(5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.set_read_timeout(timeout);
+        self.set_write_timeout(timeout);
+    }
+
+    /// Sets the time to wait before giving up a read, without changing the write one. It is kept when reconnecting.
+    ///
+    /// Ignored if the printer isn't a USB one: other transports handle timeouts themselves.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use std::time::Duration;
+    /// # let Some(mut printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Fail fast when polling the status, while large images still have time to be sent
+    /// printer.set_read_timeout(Duration::from_millis(200));
+    /// printer.set_write_timeout(Duration::from_secs(10));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_read_timeout(&mut self, timeout: Duration) {
+        if let Connection::Usb(connection, connection_data) = &mut self.connection {
+            connection.read_timeout = timeout;
+            connection_data.read_timeout = timeout;
+        }
+    }
+
+    /// Sets the time to wait before giving up a write, without changing the read one, see
+    /// [`Printer::set_read_timeout`]
+    pub fn set_write_timeout(&mut self, timeout: Duration) {
+        if let Connection::Usb(connection, connection_data) = &mut self.connection {
+            connection.write_timeout = timeout;
+            connection_data.write_timeout = timeout;
+        }
+    }
+
+    /// Reads bytes from the printer. Fails on write-only printers, which have no read endpoint.
     /// ```rust,no_run
     /// # use escpos_rw::{Error, Printer};
     /// # fn main() -> Result<(), Error> {
@@ -200,17 +1007,626 @@ impl Printer {
     /// # }
     /// ```
     pub fn read_raw(&self) -> Result<[u8; 128], Error> {
-        let PrinterConnection {
-                endpoint: _,
-                endpoint_r,
-                dh,
-                timeout,
-            } = &self.printer_connection;
+        self.read_raw_counted().map(|(buffer, _)| buffer)
+    }
+
+    /// Reads bytes from the printer, like [`Printer::read_raw`], also returning how many bytes were actually read.
+    ///
+    /// The bytes past this count are zeros, not data sent by the printer.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let (buffer, read) = printer.read_raw_counted()?;
+    /// let data = &buffer[..read];
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_raw_counted(&self) -> Result<([u8; 128], usize), Error> {
+        let mut buffer: [u8; 128] = [0; 128];
+        let read = self.read(&mut buffer)?;
+        Ok((buffer, read))
+    }
+
+    /// Reads bytes from the printer, like [`Printer::read_raw_counted`], waiting at most `timeout` instead of the
+    /// printer's read timeout
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use std::time::Duration;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let (buffer, read) = printer.read_raw_timeout(Duration::from_millis(100))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_raw_timeout(&self, timeout: Duration) -> Result<([u8; 128], usize), Error> {
+        let mut buffer: [u8; 128] = [0; 128];
+        let read = match &self.connection {
+            Connection::Usb(..) => self.transfer(timeout)?.read(&mut buffer)?,
+            Connection::Transport(_) => self.read(&mut buffer)?,
+        };
+        Ok((buffer, read))
+    }
+
+    /// Reads bytes from the printer into `buffer`, returning how many were read
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Paper sensor status
+    /// printer.write_raw([0x10, 0x04, 0x04])?;
+    /// let mut status = [0; 1];
+    /// let read = printer.read(&mut status)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        match &self.connection {
+            Connection::Usb(connection, _) => self.transfer(connection.read_timeout)?.read(buffer),
+            Connection::Transport(transport) => {
+                let read = transport.read(buffer)?;
+                log_debug!("Read {} bytes from the transport", read);
+                Ok(read)
+            }
+        }
+    }
+
+    /// Reads a response arriving in several transfers, until `terminator` is read (included in the returned bytes)
+    /// or `max_len` bytes are. Fails with [`Error::Timeout`] if neither happened within `deadline`.
+    ///
+    /// Responses like the printer id ones (`GS I`) may not fit in a single transfer. Bytes read past the terminator
+    /// are discarded.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use std::time::Duration;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Firmware version: "_", the version, then NUL
+    /// printer.write_raw([0x1D, 0x49, 0x41])?;
+    /// let version = printer.read_until(0x00, 80, Duration::from_secs(1))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_until(
+        &self,
+        terminator: u8,
+        max_len: usize,
+        deadline: Duration,
+    ) -> Result<Vec<u8>, Error> {
+        let start = Instant::now();
+        let mut response = Vec::new();
+        // Whole packets are read, so that the printer can't send more than the buffer holds
+        let mut buffer = vec![0; self.max_packet_size().unwrap_or(64) as usize];
+        while response.len() < max_len {
+            let Some(remaining) = remaining_time(deadline, start.elapsed()) else {
+                return Err(Error::Timeout);
+            };
+            let read = match self.read_within(&mut buffer, remaining) {
+                Ok(read) => read,
+                Err(Error::Timeout) => continue,
+                Err(e) => return Err(e),
+            };
+            if read == 0 {
+                std::thread::sleep(READ_POLL_INTERVAL);
+                continue;
+            }
+            response.extend_from_slice(&buffer[..read]);
+            if let Some(position) = response.iter().position(|b| *b == terminator) {
+                response.truncate(position + 1);
+                return Ok(response);
+            }
+        }
+        response.truncate(max_len);
+        Ok(response)
+    }
+
+    /// Retries transfers failing with a transient error, instead of failing at once (no retries by default).
+    ///
+    /// The first retry happens after `backoff`, and each following one waits twice as long as the previous one.
+    /// Transient errors are [`rusb::Error::Timeout`], [`rusb::Error::Pipe`] (endpoint stalled),
+    /// [`rusb::Error::Busy`], [`rusb::Error::Interrupted`] and [`rusb::Error::Overflow`]; others, like
+    /// [`rusb::Error::NoDevice`] or [`rusb::Error::Access`], are returned immediately.
+    ///
+    /// Writes are retried chunk by chunk (see [`Printer::write_raw_timeout`]), so a retried chunk may be printed twice
+    /// if the printer received it but the acknowledgement was lost. [`Printer::drain_input`] and status polling don't
+    /// retry, as they expect timeouts.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use std::time::Duration;
+    /// # let Some(mut printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Long cable runs: retry 3 times, after 50, 100 and 200 ms
+    /// printer.set_retry_policy(3, Duration::from_millis(50));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_retry_policy(&mut self, retries: u32, backoff: Duration) {
+        self.retries = retries;
+        self.retry_backoff = backoff;
+    }
+
+    /// Sets the pause made after each write to a USB printer, or each chunk of a large write (10ms by default).
+    /// Slow printers may lose data without a longer one, `Duration::ZERO` disables it.
+    ///
+    /// With the `no-op-delay` feature, the pause is compiled out: this does nothing.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use std::time::Duration;
+    /// # let Some(mut printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_op_delay(Duration::from_millis(25));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_op_delay(&mut self, delay: Duration) {
+        #[cfg(not(feature = "no-op-delay"))]
         {
-            let mut buffer: [u8; 128] = [0; 128];
-            dh.read_bulk(*endpoint_r, &mut buffer, *timeout)
-                .map_err(Error::UsbError)?;
-            Ok(buffer)
+            self.op_delay = delay;
+        }
+        #[cfg(feature = "no-op-delay")]
+        let _ = delay;
+    }
+
+    /// Pause made after each write, see [`Printer::set_op_delay`]. Always zero with the `no-op-delay` feature.
+    pub fn op_delay(&self) -> Duration {
+        #[cfg(not(feature = "no-op-delay"))]
+        return self.op_delay;
+        #[cfg(feature = "no-op-delay")]
+        Duration::ZERO
+    }
+
+    /// Returns a human readable summary of the connection, to paste in bug reports
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// println!("{}", printer.describe());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn describe(&self) -> String {
+        let Connection::Usb(printer_connection, connection_data) = &self.connection else {
+            return "Not a USB printer".to_string();
+        };
+        let PrinterConnection {
+            endpoint,
+            endpoint_r,
+            interface,
+            max_packet_size: _,
+            write_chunk_size: _,
+            detached_kernel_driver: _,
+            dh,
+            read_timeout,
+            write_timeout,
+        } = printer_connection;
+        let device = dh.device();
+        let serial = device
+            .device_descriptor()
+            .and_then(|descriptor| dh.read_serial_number_string_ascii(&descriptor))
+            .unwrap_or_else(|_| "unknown".to_string());
+        // Transfer type and max packet size of an endpoint, from the active configuration
+        let endpoint_details = |address: u8| {
+            let Ok(config_descriptor) = device.active_config_descriptor() else {
+                return "unknown".to_string();
+            };
+            for interface in config_descriptor.interfaces() {
+                for descriptor in interface.descriptors() {
+                    for endpoint in descriptor.endpoint_descriptors() {
+                        if endpoint.address() == address {
+                            return format!(
+                                "{:?}, {} bytes packets",
+                                endpoint.transfer_type(),
+                                endpoint.max_packet_size()
+                            );
+                        }
+                    }
+                }
+            }
+            "not found in the active configuration".to_string()
+        };
+        let read_endpoint = match endpoint_r {
+            Some(address) => format!("0x{:02x} ({})", address, endpoint_details(*address)),
+            None => "none, the printer is write-only".to_string(),
+        };
+        format!(
+            "VID/PID: {:04x}:{:04x}\n\
+             Serial: {}\n\
+             Bus {:03} address {:03}, interface {}\n\
+             Write endpoint: 0x{:02x} ({})\n\
+             Read endpoint: {}\n\
+             Read timeout: {:?}\n\
+             Write timeout: {:?}\n\
+             Write delay: {:?}",
+            connection_data.vendor_id,
+            connection_data.product_id,
+            serial,
+            device.bus_number(),
+            device.address(),
+            interface,
+            endpoint,
+            endpoint_details(*endpoint),
+            read_endpoint,
+            read_timeout,
+            write_timeout,
+            self.op_delay()
+        )
+    }
+
+    /// Default size of the chunks read by [`Printer::drain_input`]
+    pub const DEFAULT_READ_CHUNK: usize = 64;
+
+    /// Reads and discards whatever the printer sent that is still pending, like a stale status reply.
+    /// Returns the number of discarded bytes.
+    ///
+    /// Data is read `chunk` bytes at a time (0 selects [`Printer::DEFAULT_READ_CHUNK`]): larger chunks drain long
+    /// responses faster.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.drain_input(Printer::DEFAULT_READ_CHUNK)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn drain_input(&self, chunk: usize) -> Result<usize, Error> {
+        let chunk = if chunk == 0 {
+            Printer::DEFAULT_READ_CHUNK
+        } else {
+            chunk
+        };
+        let mut buffer = vec![0; chunk];
+        let mut drained = 0;
+        loop {
+            match self.read_within(&mut buffer, DRAIN_TIMEOUT) {
+                Ok(0) | Err(Error::Timeout) => return Ok(drained),
+                Ok(n) => drained += n,
+                Err(e) => return Err(e),
+            }
         }
     }
+
+    /// The USB connection, or an error for printers using another transport
+    fn usb(&self) -> Result<&PrinterConnection, Error> {
+        match &self.connection {
+            Connection::Usb(connection, _) => Ok(connection),
+            Connection::Transport(_) => Err(not_usb()),
+        }
+    }
+
+    /// Handle of the opened device
+    pub(crate) fn device_handle(&self) -> Result<&DeviceHandle<Context>, Error> {
+        Ok(&self.usb()?.dh)
+    }
+
+    /// Transfers to and from the bulk endpoints, waiting at most `timeout` for each of them
+    pub(crate) fn transfer(&self, timeout: Duration) -> Result<Transfer, Error> {
+        let connection = self.usb()?;
+        Ok(Transfer {
+            dh: Arc::clone(&connection.dh),
+            endpoint: connection.endpoint,
+            endpoint_r: connection.endpoint_r,
+            max_packet_size: connection.max_packet_size,
+            write_chunk_size: connection.write_chunk_size,
+            timeout,
+            retries: self.retries,
+            retry_backoff: self.retry_backoff,
+            #[cfg(not(feature = "no-op-delay"))]
+            op_delay: self.op_delay,
+        })
+    }
+
+    /// Reads bytes from the printer, waiting at most `timeout`
+    pub(crate) fn read_within(&self, buffer: &mut [u8], timeout: Duration) -> Result<usize, Error> {
+        let printer_connection = match &self.connection {
+            Connection::Usb(printer_connection, _) => printer_connection,
+            Connection::Transport(transport) => {
+                let read = transport.read(buffer)?;
+                log_debug!("Read {} bytes from the transport", read);
+                return Ok(read);
+            }
+        };
+        let PrinterConnection {
+            endpoint: _,
+            endpoint_r,
+            interface: _,
+            max_packet_size: _,
+            write_chunk_size: _,
+            detached_kernel_driver: _,
+            dh,
+            read_timeout: _,
+            write_timeout: _,
+        } = printer_connection;
+        let endpoint_r = read_endpoint(*endpoint_r)?;
+        let read = dh
+            .read_bulk(endpoint_r, buffer, timeout)
+            .map_err(Error::from_transfer)?;
+        log_debug!("Read {} bytes from endpoint {:#04x}", read, endpoint_r);
+        Ok(read)
+    }
+}
+
+/// What transfers to and from the bulk endpoints need, which can be sent to another thread
+#[derive(Clone)]
+pub(crate) struct Transfer {
+    dh: Arc<DeviceHandle<Context>>,
+    /// Bulk write endpoint
+    endpoint: u8,
+    /// Bulk read endpoint, if the printer can send data
+    endpoint_r: Option<u8>,
+    /// Max packet size of the write endpoint
+    max_packet_size: u16,
+    /// Maximum number of bytes sent in a single bulk transfer
+    write_chunk_size: usize,
+    /// Time to wait before giving up each transfer
+    timeout: Duration,
+    /// Number of times a transfer failing with a transient error is retried
+    retries: u32,
+    /// Delay before the first retry, doubled for each following one
+    retry_backoff: Duration,
+    /// Pause after each chunk
+    #[cfg(not(feature = "no-op-delay"))]
+    op_delay: Duration,
+}
+
+impl Transport for Transfer {
+    fn write(&self, bytes: &[u8]) -> Result<(), Error> {
+        self.write_counted(bytes).map(|_| ())
+    }
+
+    /// Reads bytes into `buffer`, returning how many were read
+    fn read(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        let endpoint_r = read_endpoint(self.endpoint_r)?;
+        let read = self.retrying(|| self.dh.read_bulk(endpoint_r, buffer, self.timeout))?;
+        log_debug!("Read {} bytes from endpoint {:#04x}", read, endpoint_r);
+        Ok(read)
+    }
+}
+
+impl Transfer {
+    /// Sends bytes, in chunks followed by the write delay, and returns how many the printer accepted, checking they
+    /// were all. Chunks made of whole packets are ended with a zero-length packet.
+    pub(crate) fn write_counted(&self, bytes: &[u8]) -> Result<usize, Error> {
+        log_debug!(
+            "Writing {} bytes to endpoint {:#04x}",
+            bytes.len(),
+            self.endpoint
+        );
+        write_chunks(
+            bytes,
+            self.write_chunk_size,
+            self.max_packet_size,
+            |chunk| self.retrying(|| self.dh.write_bulk(self.endpoint, chunk, self.timeout)),
+            || {
+                #[cfg(not(feature = "no-op-delay"))]
+                if !self.op_delay.is_zero() {
+                    std::thread::sleep(self.op_delay);
+                }
+            },
+        )
+    }
+
+    /// Sends a zero-length packet, ending the current transfer
+    pub(crate) fn flush(&self) -> Result<(), Error> {
+        log_debug!(
+            "Writing a zero-length packet to endpoint {:#04x}",
+            self.endpoint
+        );
+        self.retrying(|| self.dh.write_bulk(self.endpoint, &[], self.timeout))?;
+        Ok(())
+    }
+
+    /// Runs a transfer, retrying it on transient errors according to the retry policy
+    fn retrying<T>(
+        &self,
+        mut transfer: impl FnMut() -> Result<T, rusb::Error>,
+    ) -> Result<T, Error> {
+        let mut backoff = self.retry_backoff;
+        let mut attempt = 0;
+        loop {
+            match transfer() {
+                Err(
+                    rusb::Error::Timeout
+                    | rusb::Error::Pipe
+                    | rusb::Error::Busy
+                    | rusb::Error::Interrupted
+                    | rusb::Error::Overflow,
+                ) if attempt < self.retries => {
+                    attempt += 1;
+                    std::thread::sleep(backoff);
+                    backoff = backoff.saturating_mul(2);
+                }
+                result => return result.map_err(Error::from_transfer),
+            }
+        }
+    }
+}
+
+impl Drop for Printer {
+    /// Releases the interface, and reattaches the kernel driver if it was detached, so that other programs can use
+    /// the printer
+    fn drop(&mut self) {
+        if let Connection::Usb(connection, _) = &mut self.connection {
+            connection.release();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes with `write_chunks`, the printer accepting at most `accepted` bytes of each chunk. Returns the result and
+    /// the lengths of the chunks sent.
+    fn write_accepting(
+        len: usize,
+        chunk_size: usize,
+        accepted: usize,
+    ) -> (Result<usize, Error>, Vec<usize>) {
+        let mut sent = Vec::new();
+        let result = write_chunks(
+            &vec![0; len],
+            chunk_size,
+            64,
+            |chunk| {
+                sent.push(chunk.len());
+                Ok(chunk.len().min(accepted))
+            },
+            || {},
+        );
+        (result, sent)
+    }
+
+    #[test]
+    fn write_chunks_counts_accepted_bytes() {
+        let (result, sent) = write_accepting(300, 100, 100);
+        assert_eq!(result, Ok(300));
+        assert_eq!(sent, [100, 100, 100]);
+    }
+
+    #[test]
+    fn write_chunks_short_write() {
+        let (result, sent) = write_accepting(300, 100, 60);
+        assert_eq!(
+            result,
+            Err(Error::ShortWrite {
+                written: 60,
+                expected: 300
+            })
+        );
+        // The following chunks aren't sent
+        assert_eq!(sent, [100]);
+    }
+
+    #[test]
+    fn write_chunks_short_last_chunk() {
+        let (result, _) = write_accepting(250, 100, 40);
+        assert!(matches!(result, Err(Error::ShortWrite { written: 40, .. })));
+        let mut accepted = [100, 100, 20].into_iter();
+        let result = write_chunks(&[0; 250], 100, 64, |_| Ok(accepted.next().unwrap()), || {});
+        assert_eq!(
+            result,
+            Err(Error::ShortWrite {
+                written: 220,
+                expected: 250
+            })
+        );
+    }
+
+    #[test]
+    fn write_plan_exact_multiple_of_packet_size() {
+        assert_eq!(write_plan(128, 4096, 64), [(128, true)]);
+        assert_eq!(write_plan(64, 4096, 64), [(64, true)]);
+    }
+
+    #[test]
+    fn write_plan_one_byte_short_of_packet_size() {
+        assert_eq!(write_plan(127, 4096, 64), [(127, false)]);
+        assert_eq!(write_plan(63, 4096, 64), [(63, false)]);
+    }
+
+    #[test]
+    fn write_plan_empty_payload() {
+        assert_eq!(write_plan(0, 4096, 64), []);
+    }
+
+    #[test]
+    fn write_plan_chunks() {
+        // Full chunks are whole packets, the last one isn't
+        assert_eq!(
+            write_plan(4096 * 2 + 100, 4096, 64),
+            [(4096, true), (4096, true), (100, false)]
+        );
+        // Chunks which aren't whole packets
+        assert_eq!(
+            write_plan(250, 100, 64),
+            [(100, false), (100, false), (50, false)]
+        );
+        assert_eq!(write_plan(200, 100, 50), [(100, true), (100, true)]);
+    }
+
+    #[test]
+    fn write_chunks_sends_zero_length_packets() {
+        let mut sent = Vec::new();
+        let result = write_chunks(
+            &[0; 192],
+            128,
+            64,
+            |chunk| {
+                sent.push(chunk.len());
+                Ok(chunk.len())
+            },
+            || {},
+        );
+        assert_eq!(result, Ok(192));
+        // The zero-length packet isn't counted
+        assert_eq!(sent, [128, 0, 64, 0]);
+    }
+
+    #[test]
+    fn write_chunks_propagates_errors() {
+        let result = write_chunks(&[0; 10], 100, 64, |_| Err(Error::Timeout), || {});
+        assert_eq!(result, Err(Error::Timeout));
+    }
+
+    #[test]
+    fn remaining_time_within_deadline() {
+        let deadline = Duration::from_millis(100);
+        assert_eq!(
+            remaining_time(deadline, Duration::from_millis(40)),
+            Some(Duration::from_millis(60))
+        );
+        assert_eq!(
+            remaining_time(deadline, Duration::from_millis(99)),
+            Some(Duration::from_millis(1))
+        );
+    }
+
+    #[test]
+    fn remaining_time_under_a_millisecond_is_over() {
+        let deadline = Duration::from_millis(100);
+        // Would be truncated to a 0ms USB timeout, waiting forever
+        assert_eq!(
+            remaining_time(deadline, Duration::from_micros(99_001)),
+            None
+        );
+        assert_eq!(
+            remaining_time(deadline, Duration::from_micros(99_999)),
+            None
+        );
+        assert_eq!(remaining_time(deadline, deadline), None);
+        assert_eq!(remaining_time(deadline, Duration::from_millis(150)), None);
+        assert_eq!(remaining_time(Duration::ZERO, Duration::ZERO), None);
+    }
 }
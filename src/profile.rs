@@ -0,0 +1,243 @@
+use crate::{CutMode, Font, Printer};
+use std::{collections::HashMap, time::Duration};
+
+/// Default printable width, in dots, of a 80mm printer
+const DEFAULT_WIDTH: u16 = 576;
+/// Default number of lines fed before cutting, bringing the last printed line past the cutter
+const DEFAULT_CUT_FEED_LINES: u8 = 4;
+
+/// Connection, paper and layout characteristics of a printer.
+///
+/// The USB settings are used by [`Printer::open`]; endpoints are detected and the default timeout used when unset.
+/// With the `serde` feature, it can be loaded from a configuration file, unset fields keeping their default value.
+/// The timeout is given in milliseconds, and unset USB settings are left out when serializing:
+/// ```toml
+/// vendor_id = 0x04b8
+/// product_id = 0x0202
+/// write_endpoint = 0x01
+/// timeout_ms = 5000
+/// width = 512
+/// cut_mode = "Partial"
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrinterProfile {
+    /// USB vendor id
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    vendor_id: Option<u16>,
+    /// USB product id
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    product_id: Option<u16>,
+    /// Bulk write endpoint, detected if unset
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    write_endpoint: Option<u8>,
+    /// Bulk read endpoint, detected if unset
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    read_endpoint: Option<u8>,
+    /// Time to wait before giving up a read or a write, in milliseconds
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    timeout_ms: Option<u64>,
+    /// Printable width, in dots
+    width: u16,
+    /// Columns per line for each font, when they differ from what the width gives
+    columns_per_font: HashMap<Font, u8>,
+    /// Lines fed at the end of a receipt, before cutting
+    cut_feed_lines: u8,
+    /// Cut made at the end of a receipt
+    cut_mode: CutMode,
+}
+
+impl PrinterProfile {
+    /// Creates a profile builder
+    /// ```rust
+    /// use escpos_rw::{Font, PrinterProfile};
+    /// // A 58mm printer
+    /// let profile = PrinterProfile::builder().width(384).build();
+    /// assert_eq!(profile.columns(Font::FontA), 32);
+    /// assert_eq!(profile.columns(Font::FontB), 42);
+    /// ```
+    pub fn builder() -> PrinterProfileBuilder {
+        PrinterProfileBuilder {
+            profile: PrinterProfile::default(),
+        }
+    }
+
+    /// USB vendor and product ids, if both are set
+    pub fn vp_id(&self) -> Option<(u16, u16)> {
+        Some((self.vendor_id?, self.product_id?))
+    }
+
+    /// Bulk write endpoint, if set
+    pub fn write_endpoint(&self) -> Option<u8> {
+        self.write_endpoint
+    }
+
+    /// Bulk read endpoint, if set
+    pub fn read_endpoint(&self) -> Option<u8> {
+        self.read_endpoint
+    }
+
+    /// Time to wait before giving up a read or a write, if set
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_ms.map(Duration::from_millis)
+    }
+
+    /// Printable width, in dots
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Number of characters a line holds with the given font
+    pub fn columns(&self, font: Font) -> u8 {
+        match self.columns_per_font.get(&font) {
+            Some(columns) => *columns,
+            None => (self.width / font.dots_width()).min(u8::MAX as u16) as u8,
+        }
+    }
+
+    /// Lines fed at the end of a receipt, before cutting
+    pub fn cut_feed_lines(&self) -> u8 {
+        self.cut_feed_lines
+    }
+
+    /// Cut made at the end of a receipt
+    pub fn cut_mode(&self) -> CutMode {
+        self.cut_mode
+    }
+}
+
+impl Default for PrinterProfile {
+    fn default() -> Self {
+        PrinterProfile {
+            vendor_id: None,
+            product_id: None,
+            write_endpoint: None,
+            read_endpoint: None,
+            timeout_ms: None,
+            width: DEFAULT_WIDTH,
+            columns_per_font: HashMap::new(),
+            cut_feed_lines: DEFAULT_CUT_FEED_LINES,
+            cut_mode: CutMode::default(),
+        }
+    }
+}
+
+/// Builds a [`PrinterProfile`]. Unless specified, a 576 dots (80mm) width is used, the columns are computed from
+/// the width, and receipts end with 4 lines fed before a full cut. No USB ids are set.
+pub struct PrinterProfileBuilder {
+    profile: PrinterProfile,
+}
+
+impl PrinterProfileBuilder {
+    /// Sets the USB vendor and product ids
+    pub fn vp_id(mut self, vendor_id: u16, product_id: u16) -> Self {
+        self.profile.vendor_id = Some(vendor_id);
+        self.profile.product_id = Some(product_id);
+        self
+    }
+
+    /// Sets the bulk write endpoint
+    pub fn write_endpoint(mut self, endpoint: u8) -> Self {
+        self.profile.write_endpoint = Some(endpoint);
+        self
+    }
+
+    /// Sets the bulk read endpoint
+    pub fn read_endpoint(mut self, endpoint: u8) -> Self {
+        self.profile.read_endpoint = Some(endpoint);
+        self
+    }
+
+    /// Sets the time to wait before giving up a read or a write, rounded down to the millisecond
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.profile.timeout_ms = Some(timeout.as_millis().min(u64::MAX as u128) as u64);
+        self
+    }
+
+    /// Sets the printable width, in dots
+    pub fn width(mut self, dots: u16) -> Self {
+        self.profile.width = dots;
+        self
+    }
+
+    /// Sets the number of columns of a font
+    pub fn columns(mut self, font: Font, columns: u8) -> Self {
+        self.profile.columns_per_font.insert(font, columns);
+        self
+    }
+
+    /// Sets the number of lines fed at the end of a receipt, before cutting
+    pub fn cut_feed_lines(mut self, lines: u8) -> Self {
+        self.profile.cut_feed_lines = lines;
+        self
+    }
+
+    /// Sets the cut made at the end of a receipt
+    pub fn cut_mode(mut self, mode: CutMode) -> Self {
+        self.profile.cut_mode = mode;
+        self
+    }
+
+    /// Builds the profile
+    pub fn build(self) -> PrinterProfile {
+        self.profile
+    }
+}
+
+impl Printer {
+    /// Profile used for layout
+    pub fn profile(&self) -> &PrinterProfile {
+        &self.profile
+    }
+
+    /// Sets the profile used for layout
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::PrinterProfile;
+    /// # let Some(mut printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_profile(PrinterProfile::builder().width(384).build());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_profile(&mut self, profile: PrinterProfile) {
+        self.profile = profile;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usb_settings() {
+        let profile = PrinterProfile::builder()
+            .vp_id(0x04b8, 0x0202)
+            .write_endpoint(0x01)
+            .read_endpoint(0x82)
+            .timeout(Duration::from_millis(1500))
+            .build();
+        assert_eq!(profile.vp_id(), Some((0x04b8, 0x0202)));
+        assert_eq!(profile.write_endpoint(), Some(0x01));
+        assert_eq!(profile.read_endpoint(), Some(0x82));
+        assert_eq!(profile.timeout(), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn default_has_no_usb_settings() {
+        let profile = PrinterProfile::default();
+        assert_eq!(profile.vp_id(), None);
+        assert_eq!(profile.write_endpoint(), None);
+        assert_eq!(profile.timeout(), None);
+    }
+
+    #[test]
+    fn open_needs_usb_ids() {
+        assert!(Printer::open(PrinterProfile::default()).is_err());
+    }
+}
@@ -0,0 +1,358 @@
+use crate::{Error, Justification, Printer};
+
+/// QR code models
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QrModel {
+    /// Model 1, the original specification, up to version 14
+    Model1,
+    /// Model 2, read by all current scanners, up to version 40
+    #[default]
+    Model2,
+}
+
+/// QR code error correction levels, the share of the symbol which can be damaged and still read
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QrErrorCorrection {
+    /// About 7%
+    L,
+    /// About 15%
+    #[default]
+    M,
+    /// About 25%
+    Q,
+    /// About 30%
+    H,
+}
+
+impl QrErrorCorrection {
+    /// Position in the capacity tables
+    fn index(self) -> usize {
+        match self {
+            QrErrorCorrection::L => 0,
+            QrErrorCorrection::M => 1,
+            QrErrorCorrection::Q => 2,
+            QrErrorCorrection::H => 3,
+        }
+    }
+}
+
+/// Module size giving QR codes phones scan reliably from a receipt, the largest used for URLs
+const URL_MODULE_SIZE: u8 = 6;
+
+/// Byte data capacity of each model 2 symbol version, from 1 to 40, with the medium error correction level
+const URL_VERSION_CAPACITY: [usize; 40] = [
+    14, 26, 42, 62, 84, 106, 122, 152, 180, 213, 251, 287, 331, 362, 412, 450, 504, 560, 624, 666,
+    711, 779, 857, 911, 997, 1059, 1125, 1190, 1264, 1370, 1452, 1538, 1628, 1722, 1809, 1911,
+    1989, 2099, 2213, 2331,
+];
+
+/// Capacity of the largest model 2 symbol (version 40) for numeric data, by error correction level (L, M, Q, H)
+const NUMERIC_CAPACITY: [usize; 4] = [7089, 5596, 3993, 3057];
+/// Capacity of the largest model 2 symbol for alphanumeric data (digits, uppercase letters and ` $%*+-./:`)
+const ALPHANUMERIC_CAPACITY: [usize; 4] = [4296, 3391, 2420, 1852];
+/// Capacity of the largest model 2 symbol for any other data
+const BYTE_CAPACITY: [usize; 4] = [2953, 2331, 1663, 1273];
+/// Capacity of the largest model 1 symbol (version 14) for numeric data
+const MODEL1_NUMERIC_CAPACITY: [usize; 4] = [1167, 919, 631, 483];
+/// Capacity of the largest model 1 symbol for alphanumeric data
+const MODEL1_ALPHANUMERIC_CAPACITY: [usize; 4] = [707, 557, 383, 293];
+/// Capacity of the largest model 1 symbol for any other data
+const MODEL1_BYTE_CAPACITY: [usize; 4] = [486, 382, 262, 201];
+
+/// Checks the data fits in a QR code of this model, with the given error correction level
+fn check_capacity(data: &str, model: QrModel, ec: QrErrorCorrection) -> Result<(), Error> {
+    if data.is_empty() {
+        return Err(Error::PrinterError("QR code data is empty".to_string()));
+    }
+    let (numeric, alphanumeric, byte) = match model {
+        QrModel::Model1 => (
+            MODEL1_NUMERIC_CAPACITY,
+            MODEL1_ALPHANUMERIC_CAPACITY,
+            MODEL1_BYTE_CAPACITY,
+        ),
+        QrModel::Model2 => (NUMERIC_CAPACITY, ALPHANUMERIC_CAPACITY, BYTE_CAPACITY),
+    };
+    let level = ec.index();
+    let (mode, capacity) = if data.bytes().all(|b| b.is_ascii_digit()) {
+        ("numeric", numeric[level])
+    } else if data
+        .bytes()
+        .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase() || b" $%*+-./:".contains(&b))
+    {
+        ("alphanumeric", alphanumeric[level])
+    } else {
+        ("byte", byte[level])
+    };
+    // All capacities are far below the 16 bits length field limit
+    if data.len() > capacity {
+        return Err(Error::PrinterError(format!(
+            "QR code data is too long: {} bytes, the maximum is {} for {} data at this error correction level",
+            data.len(),
+            capacity,
+            mode
+        )));
+    }
+    Ok(())
+}
+
+/// Bytes of the `GS ( k` commands printing a QR code, see [`Printer::print_qr`]
+/// ```rust
+/// use escpos_rw::{qr_bytes, QrErrorCorrection, QrModel};
+/// let bytes = qr_bytes("https://example.com", QrModel::Model2, 5, QrErrorCorrection::M).unwrap();
+/// // Ends with the print command
+/// assert!(bytes.ends_with(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x51, 0x30]));
+/// assert!(qr_bytes("", QrModel::Model2, 5, QrErrorCorrection::M).is_err());
+/// ```
+pub fn qr_bytes(
+    data: &str,
+    model: QrModel,
+    size: u8,
+    ec: QrErrorCorrection,
+) -> Result<Vec<u8>, Error> {
+    if !(1..=16).contains(&size) {
+        return Err(Error::PrinterError(format!(
+            "QR code module size must be between 1 and 16, got {}",
+            size
+        )));
+    }
+    check_capacity(data, model, ec)?;
+    let model = match model {
+        QrModel::Model1 => 0x31,
+        QrModel::Model2 => 0x32,
+    };
+    let ec = 0x30 + ec.index() as u8;
+    let [p_l, p_h] = (data.len() as u16 + 3).to_le_bytes();
+    let mut bytes = Vec::with_capacity(data.len() + 40);
+    // Model
+    bytes.extend_from_slice(&[0x1D, 0x28, 0x6B, 0x04, 0x00, 0x31, 0x41, model, 0x00]);
+    // Module size
+    bytes.extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x43, size]);
+    // Error correction level
+    bytes.extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x45, ec]);
+    // Data storage
+    bytes.extend_from_slice(&[0x1D, 0x28, 0x6B, p_l, p_h, 0x31, 0x50, 0x30]);
+    bytes.extend_from_slice(data.as_bytes());
+    // Prints the stored symbol
+    bytes.extend_from_slice(&[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x51, 0x30]);
+    Ok(bytes)
+}
+
+/// Largest module size, up to [`URL_MODULE_SIZE`], with which the model 2 symbol of `url` fits in `width` dots
+fn url_module_size(url: &str, width: u16) -> Result<u8, Error> {
+    check_capacity(url, QrModel::Model2, QrErrorCorrection::M)?;
+    // Checked above to fit in version 40
+    let version = URL_VERSION_CAPACITY
+        .iter()
+        .position(|capacity| url.len() <= *capacity)
+        .unwrap_or(39)
+        + 1;
+    let modules = 17 + 4 * version as u16;
+    (1..=URL_MODULE_SIZE)
+        .rev()
+        .find(|size| modules * *size as u16 <= width)
+        .ok_or_else(|| {
+            Error::PrinterError(format!(
+                "QR code of {} modules doesn't fit in {} dots",
+                modules, width
+            ))
+        })
+}
+
+impl Printer {
+    /// Prints a QR code with the `GS ( k` commands.
+    ///
+    /// The module size, the side of a module in dots, must be in 1..=16. The largest symbol data fits in is used.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::{QrErrorCorrection, QrModel};
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.print_qr("WIFI:T:WPA;S:Shop;P:secret;;", QrModel::Model2, 5, QrErrorCorrection::Q)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_qr(
+        &self,
+        data: &str,
+        model: QrModel,
+        size: u8,
+        ec: QrErrorCorrection,
+    ) -> Result<(), Error> {
+        self.write_raw(qr_bytes(data, model, size, ec)?)
+    }
+
+    /// Prints a centered QR code for an URL, with an optional centered caption above it.
+    /// The previous justification is restored afterwards.
+    ///
+    /// Modules are 6 dots wide, or less if the symbol the URL needs wouldn't fit in the profile width. It is an
+    /// error if it doesn't fit with 1 dot modules either, and nothing is printed then.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.print_qr_url("https://example.com/feedback", Some("Tell us what you think !"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_qr_url(&self, url: &str, caption: Option<&str>) -> Result<(), Error> {
+        let size = url_module_size(url, self.profile.width())?;
+        let previous = self.justification.get();
+        self.set_justification(Justification::Center)?;
+        let printed = (|| {
+            if let Some(caption) = caption {
+                let mut line = self.code_page.get().encode(caption, b'?');
+                line.push(b'\n');
+                self.write_raw(line)?;
+            }
+            self.print_qr(url, QrModel::Model2, size, QrErrorCorrection::M)
+        })();
+        let restored = self.set_justification(previous);
+        printed.and(restored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEVELS: [QrErrorCorrection; 4] = [
+        QrErrorCorrection::L,
+        QrErrorCorrection::M,
+        QrErrorCorrection::Q,
+        QrErrorCorrection::H,
+    ];
+
+    /// Checks data of `capacity` repeated `c` fits, and one more doesn't
+    fn assert_capacity(model: QrModel, c: char, capacities: [usize; 4]) {
+        for (ec, capacity) in LEVELS.into_iter().zip(capacities) {
+            let data = c.to_string().repeat(capacity);
+            assert!(
+                check_capacity(&data, model, ec).is_ok(),
+                "{:?} {:?}",
+                model,
+                ec
+            );
+            let data = c.to_string().repeat(capacity + 1);
+            assert!(
+                check_capacity(&data, model, ec).is_err(),
+                "{:?} {:?}",
+                model,
+                ec
+            );
+        }
+    }
+
+    #[test]
+    fn model2_capacity() {
+        assert_capacity(QrModel::Model2, '7', NUMERIC_CAPACITY);
+        assert_capacity(QrModel::Model2, 'A', ALPHANUMERIC_CAPACITY);
+        assert_capacity(QrModel::Model2, 'a', BYTE_CAPACITY);
+    }
+
+    #[test]
+    fn model1_capacity() {
+        assert_capacity(QrModel::Model1, '7', MODEL1_NUMERIC_CAPACITY);
+        assert_capacity(QrModel::Model1, 'A', MODEL1_ALPHANUMERIC_CAPACITY);
+        assert_capacity(QrModel::Model1, 'a', MODEL1_BYTE_CAPACITY);
+    }
+
+    #[test]
+    fn capacity_at_the_edges() {
+        // Exactly 7089 digits at level L, the largest model 2 symbol
+        assert!(qr_bytes(&"1".repeat(7089), QrModel::Model2, 1, QrErrorCorrection::L).is_ok());
+        assert!(qr_bytes(&"1".repeat(7090), QrModel::Model2, 1, QrErrorCorrection::L).is_err());
+        // A single lowercase letter makes alphanumeric data byte data
+        let data = format!("{}a", "A".repeat(BYTE_CAPACITY[0]));
+        assert!(check_capacity(&data, QrModel::Model2, QrErrorCorrection::L).is_err());
+        // Multi-byte characters count as their UTF-8 bytes
+        let data = "é".repeat(MODEL1_BYTE_CAPACITY[3] / 2 + 1);
+        assert!(check_capacity(&data, QrModel::Model1, QrErrorCorrection::H).is_err());
+        assert!(check_capacity("", QrModel::Model1, QrErrorCorrection::L).is_err());
+    }
+
+    #[test]
+    fn qr_bytes_commands() {
+        let bytes = qr_bytes("AB", QrModel::Model1, 4, QrErrorCorrection::H).unwrap();
+        assert_eq!(
+            bytes,
+            [
+                &[0x1D, 0x28, 0x6B, 0x04, 0x00, 0x31, 0x41, 0x31, 0x00][..],
+                &[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x43, 4],
+                &[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x45, 0x33],
+                &[0x1D, 0x28, 0x6B, 0x05, 0x00, 0x31, 0x50, 0x30, b'A', b'B'],
+                &[0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x51, 0x30],
+            ]
+            .concat()
+        );
+        assert!(qr_bytes("AB", QrModel::Model2, 0, QrErrorCorrection::M).is_err());
+        assert!(qr_bytes("AB", QrModel::Model2, 17, QrErrorCorrection::M).is_err());
+    }
+
+    /// Module size sent by `print_qr_url` on a printer `width` dots wide, with what it printed
+    fn url_module_size_printed(url: &str, width: u16) -> Result<(u8, Vec<u8>), Error> {
+        let sink = crate::VecTransport::new();
+        let mut printer = Printer::with_transport(sink.clone());
+        printer.set_profile(crate::PrinterProfile::builder().width(width).build());
+        printer.print_qr_url(url, Some("Menu"))?;
+        let bytes = sink.take();
+        let at = bytes
+            .windows(7)
+            .position(|w| w == [0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x43])
+            .unwrap();
+        Ok((bytes[at + 7], bytes))
+    }
+
+    #[test]
+    fn print_qr_url_short() {
+        // Version 2, 25 modules
+        let (size, bytes) = url_module_size_printed("https://example.com/menu", 384).unwrap();
+        assert_eq!(size, 6);
+        assert!(bytes.starts_with(&[0x1B, 0x61, 1, b'M', b'e', b'n', b'u', b'\n']));
+        assert!(bytes.ends_with(&[0x1B, 0x61, 0]));
+        assert_eq!(
+            url_module_size_printed("https://example.com/menu", 160)
+                .unwrap()
+                .0,
+            6
+        );
+        assert_eq!(
+            url_module_size_printed("https://example.com/menu", 149)
+                .unwrap()
+                .0,
+            5
+        );
+    }
+
+    #[test]
+    fn print_qr_url_long_on_58mm_paper() {
+        // 300 bytes need version 13, 69 modules: 414 dots at 6 dots a module
+        let url = format!("https://example.com/?q={}", "a".repeat(277));
+        assert_eq!(url_module_size_printed(&url, 384).unwrap().0, 5);
+        assert_eq!(url_module_size_printed(&url, 576).unwrap().0, 6);
+        // Version 40, 177 modules
+        let url = format!("https://example.com/?q={}", "a".repeat(2308));
+        assert_eq!(url_module_size_printed(&url, 384).unwrap().0, 2);
+    }
+
+    #[test]
+    fn print_qr_url_too_wide() {
+        let sink = crate::VecTransport::new();
+        let mut printer = Printer::with_transport(sink.clone());
+        printer.set_profile(crate::PrinterProfile::builder().width(24).build());
+        assert!(printer
+            .print_qr_url("https://example.com", Some("Menu"))
+            .is_err());
+        // Not even the caption
+        assert!(sink.take().is_empty());
+        assert!(url_module_size("https://example.com", 25).is_ok());
+        assert!(url_module_size(&"a".repeat(2332), 576).is_err());
+    }
+}
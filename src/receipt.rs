@@ -0,0 +1,227 @@
+use crate::command::finish_commands;
+use crate::layout::section_line;
+use crate::{justification_bytes, CodePage, Error, Font, Justification, Printer, PrinterProfile};
+
+/// Part of a receipt
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    /// A line of text, with its justification
+    Line(String, Justification),
+    /// A divider line with a centered label
+    Section(String),
+    /// Raw ESC/POS bytes
+    Raw(Vec<u8>),
+}
+
+/// Builds a receipt, to be rendered to ESC/POS bytes for a given profile.
+///
+/// Rendering needs no printer: the bytes can be stored as spool files, and sent later to a printer with
+/// [`Printer::write_raw`], or printed directly with [`Printer::print_receipt`].
+/// ```rust
+/// use escpos_rw::{Justification, PrinterProfile, ReceiptBuilder};
+/// let receipt = ReceiptBuilder::new()
+///     .line_justified("MY SHOP", Justification::Center)
+///     .section("ITEMS")
+///     .line("Coffee        2.50")
+///     .line_justified("Total: 2.50", Justification::Right);
+/// // A 58mm printer
+/// let bytes = receipt.render(&PrinterProfile::builder().width(384).build());
+/// assert!(bytes.starts_with(b"\x1ba\x01MY SHOP\n\x1ba\x00"));
+/// // Ends with the feed and cut of the profile
+/// assert!(bytes.ends_with(&[0x1B, 0x64, 4, 0x1D, 0x56, 0]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReceiptBuilder {
+    items: Vec<Item>,
+    /// Code page the text is encoded with, selected at the start of the receipt if set
+    code_page: Option<CodePage>,
+}
+
+impl ReceiptBuilder {
+    /// Creates an empty receipt
+    pub fn new() -> ReceiptBuilder {
+        ReceiptBuilder::default()
+    }
+
+    /// Adds a left justified line of text. Characters the code page doesn't have are replaced with `?`.
+    pub fn line(self, text: &str) -> Self {
+        self.line_justified(text, Justification::Left)
+    }
+
+    /// Adds a line of text with the given justification. Characters the code page doesn't have are replaced with
+    /// `?`.
+    pub fn line_justified(mut self, text: &str, justification: Justification) -> Self {
+        self.items.push(Item::Line(text.to_string(), justification));
+        self
+    }
+
+    /// Adds a divider line with a centered label, like `----- ITEMS -----`, as wide as the profile allows
+    pub fn section(mut self, label: &str) -> Self {
+        self.items.push(Item::Section(label.to_string()));
+        self
+    }
+
+    /// Encodes the text with the given code page, selected (`ESC t n`) at the start of the receipt.
+    ///
+    /// Otherwise, the text is encoded with PC437 when rendered, or with the printer's active code page when printed
+    /// with [`Printer::print_receipt`], and no code page is selected.
+    /// ```rust
+    /// use escpos_rw::{CodePage, PrinterProfile, ReceiptBuilder};
+    /// let receipt = ReceiptBuilder::new().code_page(CodePage::PC858).line("Café 2,50 €");
+    /// let bytes = receipt.render(&PrinterProfile::default());
+    /// assert!(bytes.starts_with(b"\x1bt\x13Caf\x82 2,50 \xd5\n"));
+    /// ```
+    pub fn code_page(mut self, code_page: CodePage) -> Self {
+        self.code_page = Some(code_page);
+        self
+    }
+
+    /// Adds raw ESC/POS bytes
+    pub fn raw<A: AsRef<[u8]>>(mut self, bytes: A) -> Self {
+        self.items.push(Item::Raw(bytes.as_ref().to_vec()));
+        self
+    }
+
+    /// Renders the receipt for the given profile, ending with its feed and cut.
+    ///
+    /// Justified lines are followed by a return to left justification.
+    pub fn render(&self, profile: &PrinterProfile) -> Vec<u8> {
+        self.render_with(profile, CodePage::default())
+    }
+
+    /// Renders the receipt, encoding the text with `default_code_page` if it has no code page
+    fn render_with(&self, profile: &PrinterProfile, default_code_page: CodePage) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let code_page = match self.code_page {
+            Some(code_page) => {
+                bytes.extend_from_slice(&[0x1B, 0x74, code_page.number()]);
+                code_page
+            }
+            None => default_code_page,
+        };
+        for item in &self.items {
+            match item {
+                Item::Line(line, Justification::Left) => {
+                    bytes.extend(code_page.encode(line, b'?'));
+                    bytes.push(b'\n');
+                }
+                Item::Line(line, justification) => {
+                    bytes.extend_from_slice(&justification_bytes(*justification));
+                    bytes.extend(code_page.encode(line, b'?'));
+                    bytes.push(b'\n');
+                    bytes.extend_from_slice(&justification_bytes(Justification::Left));
+                }
+                Item::Section(label) => {
+                    bytes.extend(section_line(label, profile.columns(Font::FontA), code_page));
+                }
+                Item::Raw(raw) => bytes.extend_from_slice(raw),
+            }
+        }
+        bytes.extend_from_slice(&finish_commands(profile));
+        bytes
+    }
+}
+
+impl Printer {
+    /// Renders a receipt with the printer's profile, and prints it.
+    ///
+    /// The justification is left and font A selected when it returns.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::ReceiptBuilder;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.print_receipt(&ReceiptBuilder::new().line("Thank you !"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_receipt(&self, receipt: &ReceiptBuilder) -> Result<(), Error> {
+        // Start left justified with font A, as the rendered receipt assumes
+        self.set_justification(Justification::Left)?;
+        self.set_font(Font::FontA)?;
+        self.write_raw(receipt.render_with(&self.profile, self.code_page.get()))?;
+        if let Some(code_page) = receipt.code_page {
+            self.code_page.set(code_page);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CutMode, VecTransport};
+
+    /// 58mm paper, 32 columns, cut after 2 lines
+    fn profile() -> PrinterProfile {
+        PrinterProfile::builder()
+            .width(384)
+            .cut_feed_lines(2)
+            .cut_mode(CutMode::Partial)
+            .build()
+    }
+
+    #[test]
+    fn render_small_receipt() {
+        let receipt = ReceiptBuilder::new()
+            .line_justified("MY SHOP", Justification::Center)
+            .section("ITEMS")
+            .line("Coffee")
+            .raw([0x1B, 0x45, 1]);
+        let mut expected = b"\x1ba\x01MY SHOP\n\x1ba\x00".to_vec();
+        expected.extend_from_slice(b"------------ ITEMS -------------\n");
+        expected.extend_from_slice(b"Coffee\n");
+        expected.extend_from_slice(&[0x1B, 0x45, 1]);
+        expected.extend_from_slice(&[0x1B, 0x64, 2, 0x1D, 0x56, 1]);
+        assert_eq!(receipt.render(&profile()), expected);
+    }
+
+    #[test]
+    fn render_empty_receipt() {
+        assert_eq!(
+            ReceiptBuilder::new().render(&profile()),
+            [0x1B, 0x64, 2, 0x1D, 0x56, 1]
+        );
+    }
+
+    #[test]
+    fn render_without_code_page_uses_pc437() {
+        let receipt = ReceiptBuilder::new().line("Café 5 €");
+        assert!(receipt.render(&profile()).starts_with(b"Caf\x82 5 ?\n"));
+    }
+
+    #[test]
+    fn render_with_code_page() {
+        let receipt = ReceiptBuilder::new()
+            .code_page(CodePage::WPC1252)
+            .section("Crème")
+            .line_justified("5 €", Justification::Right);
+        let mut expected = vec![0x1B, 0x74, 16];
+        expected.extend_from_slice(b"------------ Cr\xe8me -------------\n");
+        expected.extend_from_slice(b"\x1ba\x025 \x80\n\x1ba\x00");
+        expected.extend_from_slice(&[0x1B, 0x64, 2, 0x1D, 0x56, 1]);
+        assert_eq!(receipt.render(&profile()), expected);
+    }
+
+    #[test]
+    fn print_receipt_uses_printer_code_page() {
+        let sink = VecTransport::new();
+        let printer = Printer::with_transport(sink.clone());
+        printer.set_code_page(CodePage::PC850).unwrap();
+        sink.take();
+        printer
+            .print_receipt(&ReceiptBuilder::new().line("Café"))
+            .unwrap();
+        // Left justification and font A, then the receipt
+        assert!(sink.take().starts_with(b"\x1ba\x00\x1bM\x00Caf\x82\n"));
+
+        printer
+            .print_receipt(&ReceiptBuilder::new().code_page(CodePage::PC858).line("€"))
+            .unwrap();
+        assert_eq!(printer.code_page(), CodePage::PC858);
+    }
+}
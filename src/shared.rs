@@ -0,0 +1,275 @@
+use crate::{
+    Barcode, CodePage, CutMode, CutResult, Error, EscposImage, Font, HriPosition, Justification,
+    Printer, PrinterStatus, QrErrorCorrection, QrModel, ReadyCriteria, ReceiptBuilder, StatusKind,
+    UnderlineMode,
+};
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A printer shared between threads. Clones share the same printer.
+///
+/// Each method locks the printer for the whole command, so that the bytes of concurrent commands don't interleave.
+/// A receipt made of several commands must be printed within a single lock, with [`SharedPrinter::lock`],
+/// [`SharedPrinter::with`] or [`SharedPrinter::print_receipt`]: otherwise, another thread's commands may end up in the
+/// middle of it.
+///
+/// The common commands are forwarded; the others are available through [`SharedPrinter::with`].
+/// ```rust
+/// use escpos_rw::{CutMode, Error, Printer, SharedPrinter, VecTransport};
+/// # fn main() -> Result<(), Error> {
+/// let sink = VecTransport::new();
+/// let printer = SharedPrinter::new(Printer::with_transport(sink.clone()));
+/// let workers = (0..4)
+///     .map(|order| {
+///         let printer = printer.clone();
+///         std::thread::spawn(move || {
+///             printer.with(|printer| {
+///                 printer.write_raw(format!("Order {}\n", order))?;
+///                 printer.cut(CutMode::Full)
+///             })
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// for worker in workers {
+///     worker.join().unwrap()?;
+/// }
+/// # assert_eq!(sink.bytes().len(), 4 * 11);
+/// # Ok(())}
+/// ```
+#[derive(Clone)]
+pub struct SharedPrinter {
+    printer: Arc<Mutex<Printer>>,
+}
+
+impl SharedPrinter {
+    /// Shares the printer
+    pub fn new(printer: Printer) -> SharedPrinter {
+        SharedPrinter {
+            printer: Arc::new(Mutex::new(printer)),
+        }
+    }
+
+    /// Locks the printer, waiting for other threads to be done with it, until the guard is dropped.
+    ///
+    /// If a thread panicked while holding the lock, the printer is handed over anyway: it may have printed part of a
+    /// receipt, but stays usable.
+    pub fn lock(&self) -> MutexGuard<'_, Printer> {
+        self.printer
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Runs `f` with the printer locked, to send several commands without other threads' coming in between
+    pub fn with<T>(&self, f: impl FnOnce(&Printer) -> T) -> T {
+        f(&self.lock())
+    }
+
+    /// Sends bytes to the printer, see [`Printer::write_raw`]
+    pub fn write_raw<A: AsRef<[u8]>>(&self, bytes: A) -> Result<(), Error> {
+        self.lock().write_raw(bytes)
+    }
+
+    /// Prints text followed by a line feed, see [`Printer::println`]
+    pub fn println(&self, text: &str, codepage: CodePage) -> Result<(), Error> {
+        self.lock().println(text, codepage)
+    }
+
+    /// Prints a whole receipt, see [`Printer::print_receipt`]
+    pub fn print_receipt(&self, receipt: &ReceiptBuilder) -> Result<(), Error> {
+        self.lock().print_receipt(receipt)
+    }
+
+    /// Cuts the paper, see [`Printer::cut`]
+    pub fn cut(&self, mode: CutMode) -> Result<(), Error> {
+        self.lock().cut(mode)
+    }
+
+    /// Ends a receipt, see [`Printer::finish`]
+    pub fn finish(&self) -> Result<(), Error> {
+        self.lock().finish()
+    }
+
+    /// Prints text encoded with the given code page, see [`Printer::print_str`]
+    pub fn print_str(&self, text: &str, codepage: CodePage) -> Result<(), Error> {
+        self.lock().print_str(text, codepage)
+    }
+
+    /// Selects the code page, see [`Printer::set_code_page`]
+    pub fn set_code_page(&self, cp: CodePage) -> Result<(), Error> {
+        self.lock().set_code_page(cp)
+    }
+
+    /// Prints a line with the given justification, see [`Printer::println_justified`]
+    pub fn println_justified(&self, text: &str, justification: Justification) -> Result<(), Error> {
+        self.lock().println_justified(text, justification)
+    }
+
+    /// Prints a divider line with a centered label, see [`Printer::section`]
+    pub fn section(&self, label: &str) -> Result<(), Error> {
+        self.lock().section(label)
+    }
+
+    /// Prints a line with text on the left and on the right, see [`Printer::print_row`]
+    pub fn print_row(&self, left: &str, right: &str, font: Font) -> Result<(), Error> {
+        self.lock().print_row(left, right, font)
+    }
+
+    /// Sets the justification, see [`Printer::set_justification`]
+    pub fn set_justification(&self, justification: Justification) -> Result<(), Error> {
+        self.lock().set_justification(justification)
+    }
+
+    /// Turns bold text on or off, see [`Printer::set_bold`]
+    pub fn set_bold(&self, on: bool) -> Result<(), Error> {
+        self.lock().set_bold(on)
+    }
+
+    /// Sets the underline mode, see [`Printer::set_underline`]
+    pub fn set_underline(&self, mode: UnderlineMode) -> Result<(), Error> {
+        self.lock().set_underline(mode)
+    }
+
+    /// Turns white on black printing on or off, see [`Printer::set_inverse`]
+    pub fn set_inverse(&self, on: bool) -> Result<(), Error> {
+        self.lock().set_inverse(on)
+    }
+
+    /// Sets the character magnification, see [`Printer::set_char_size`]
+    pub fn set_char_size(&self, width: u8, height: u8) -> Result<(), Error> {
+        self.lock().set_char_size(width, height)
+    }
+
+    /// Selects the font, see [`Printer::set_font`]
+    pub fn set_font(&self, font: Font) -> Result<(), Error> {
+        self.lock().set_font(font)
+    }
+
+    /// Resets the printer, see [`Printer::initialize`]
+    pub fn initialize(&self) -> Result<(), Error> {
+        self.lock().initialize()
+    }
+
+    /// Feeds `n` lines, see [`Printer::feed_lines`]
+    pub fn feed_lines(&self, n: u8) -> Result<(), Error> {
+        self.lock().feed_lines(n)
+    }
+
+    /// Opens the cash drawer with the default pulse, see [`Printer::open_cash_drawer_default`]
+    pub fn open_cash_drawer_default(&self) -> Result<(), Error> {
+        self.lock().open_cash_drawer_default()
+    }
+
+    /// Prints an image, see [`Printer::print_image`]
+    pub fn print_image(&self, image: &EscposImage) -> Result<(), Error> {
+        self.lock().print_image(image)
+    }
+
+    /// Prints a barcode, see [`Printer::print_barcode`]
+    pub fn print_barcode(
+        &self,
+        sym: Barcode,
+        data: &str,
+        height: u8,
+        width: u8,
+        hri: HriPosition,
+    ) -> Result<(), Error> {
+        self.lock().print_barcode(sym, data, height, width, hri)
+    }
+
+    /// Prints a shelf label, see [`Printer::print_product_barcode`]
+    pub fn print_product_barcode(&self, upc: &str, name: &str, price: &str) -> Result<(), Error> {
+        self.lock().print_product_barcode(upc, name, price)
+    }
+
+    /// Prints a QR code, see [`Printer::print_qr`]
+    pub fn print_qr(
+        &self,
+        data: &str,
+        model: QrModel,
+        size: u8,
+        ec: QrErrorCorrection,
+    ) -> Result<(), Error> {
+        self.lock().print_qr(data, model, size, ec)
+    }
+
+    /// Prints a centered QR code for an URL, see [`Printer::print_qr_url`]
+    pub fn print_qr_url(&self, url: &str, caption: Option<&str>) -> Result<(), Error> {
+        self.lock().print_qr_url(url, caption)
+    }
+
+    /// Queries the printer's real-time status, see [`Printer::status`]
+    pub fn status(&self, kind: StatusKind) -> Result<PrinterStatus, Error> {
+        self.lock().status(kind)
+    }
+
+    /// Checks a cut succeeded, see [`Printer::verify_cut`]
+    pub fn verify_cut(&self) -> Result<CutResult, Error> {
+        self.lock().verify_cut()
+    }
+
+    /// Returns true if the printer meets the given criteria, see [`Printer::is_ready`]
+    pub fn is_ready(&self, criteria: ReadyCriteria) -> Result<bool, Error> {
+        self.lock().is_ready(criteria)
+    }
+
+    /// Waits until the printer meets the given criteria, see [`Printer::wait_until_ready`].
+    ///
+    /// The printer is only locked while polling: other threads can use it in between.
+    pub fn wait_until_ready(
+        &self,
+        criteria: ReadyCriteria,
+        poll_interval: Duration,
+        max_wait: Duration,
+    ) -> Result<(), Error> {
+        let start = Instant::now();
+        loop {
+            if self.is_ready(criteria)? {
+                return Ok(());
+            }
+            if start.elapsed() >= max_wait {
+                return Err(Error::Timeout);
+            }
+            thread::sleep(poll_interval);
+        }
+    }
+}
+
+impl From<Printer> for SharedPrinter {
+    fn from(printer: Printer) -> SharedPrinter {
+        SharedPrinter::new(printer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VecTransport;
+
+    #[test]
+    fn forwards_commands() {
+        let sink = VecTransport::new();
+        let printer = SharedPrinter::new(Printer::with_transport(sink.clone()));
+        printer.set_justification(Justification::Center).unwrap();
+        printer.set_bold(true).unwrap();
+        printer.set_char_size(2, 2).unwrap();
+        assert_eq!(sink.take(), b"\x1ba\x01\x1bE\x01\x1d!\x11");
+        assert_eq!(printer.lock().justification.get(), Justification::Center);
+    }
+
+    #[test]
+    fn forwards_barcodes_and_qr_codes() {
+        let sink = VecTransport::new();
+        let printer = SharedPrinter::new(Printer::with_transport(sink.clone()));
+        printer
+            .print_barcode(Barcode::Code39, "ABC", 80, 2, HriPosition::Below)
+            .unwrap();
+        assert!(sink.take().ends_with(b"\x1dkE\x03ABC"));
+        printer
+            .print_qr("HELLO", QrModel::Model2, 4, QrErrorCorrection::M)
+            .unwrap();
+        assert!(!sink.take().is_empty());
+    }
+}
@@ -0,0 +1,741 @@
+use crate::{Error, Printer};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Real-time status requests, sent with `DLE EOT n`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusKind {
+    /// Printer status (n = 1)
+    Printer,
+    /// Offline cause status (n = 2)
+    OfflineCause,
+    /// Error cause status (n = 3)
+    Error,
+    /// Roll paper sensor status (n = 4)
+    PaperSensor,
+}
+
+impl StatusKind {
+    fn as_byte(self) -> u8 {
+        match self {
+            StatusKind::Printer => 1,
+            StatusKind::OfflineCause => 2,
+            StatusKind::Error => 3,
+            StatusKind::PaperSensor => 4,
+        }
+    }
+}
+
+/// Decoded status flags.
+///
+/// A status reply only carries the flags of the requested [`StatusKind`], all the other flags are left to false.
+///
+/// About errors:
+/// - automatically recoverable errors (print head overheating, cover opened while printing on some models)
+///   clear themselves once their cause is gone.
+/// - cutter errors (a paper jam in the autocutter) are recoverable: once the jam is cleared, the printer stays in
+///   the error state until [`Printer::recover_from_error`] is called.
+/// - unrecoverable errors (head or voltage failures) require the printer to be switched off.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrinterStatus {
+    /// Drawer kick-out connector pin 3 is high ([`StatusKind::Printer`])
+    pub drawer_open: bool,
+    /// Printer is offline ([`StatusKind::Printer`])
+    pub offline: bool,
+    /// Printer is waiting for online recovery ([`StatusKind::Printer`])
+    pub waiting_for_recovery: bool,
+    /// Paper feed button is being pressed ([`StatusKind::Printer`])
+    pub feed_button_pressed: bool,
+    /// Cover is open ([`StatusKind::OfflineCause`])
+    pub cover_open: bool,
+    /// Paper is being fed by the feed button ([`StatusKind::OfflineCause`])
+    pub paper_feeding: bool,
+    /// Printing stopped because of a paper end ([`StatusKind::OfflineCause`])
+    pub paper_end_stop: bool,
+    /// An error occurred ([`StatusKind::OfflineCause`])
+    pub error: bool,
+    /// Mechanical error, on models reporting it ([`StatusKind::Error`])
+    pub mechanical_error: bool,
+    /// Autocutter error, usually a paper jam ([`StatusKind::Error`])
+    pub cutter_error: bool,
+    /// Unrecoverable error ([`StatusKind::Error`])
+    pub unrecoverable_error: bool,
+    /// Automatically recoverable error, like print head overheating ([`StatusKind::Error`])
+    pub auto_recoverable_error: bool,
+    /// Roll paper is near its end ([`StatusKind::PaperSensor`])
+    pub paper_near_end: bool,
+    /// Roll paper is not present ([`StatusKind::PaperSensor`])
+    pub paper_out: bool,
+}
+
+impl PrinterStatus {
+    /// Decodes a status byte sent back by the printer in reply to a `DLE EOT n` request
+    /// ```rust
+    /// use escpos_rw::{PrinterStatus, StatusKind};
+    /// # fn main() -> Result<(), escpos_rw::Error> {
+    /// let status = PrinterStatus::from_byte(StatusKind::Error, 0x1A)?;
+    /// assert!(status.cutter_error);
+    /// assert!(!status.auto_recoverable_error);
+    /// # Ok(())}
+    /// ```
+    pub fn from_byte(kind: StatusKind, byte: u8) -> Result<PrinterStatus, Error> {
+        // Bits 1 and 4 are always set, bits 0 and 7 always cleared
+        if byte & 0x93 != 0x12 {
+            return Err(Error::PrinterError(format!(
+                "Invalid status byte: {:#04x}",
+                byte
+            )));
+        }
+        let bit = |n: u8| byte & (1 << n) != 0;
+        let mut status = PrinterStatus::default();
+        match kind {
+            StatusKind::Printer => {
+                status.drawer_open = bit(2);
+                status.offline = bit(3);
+                status.waiting_for_recovery = bit(5);
+                status.feed_button_pressed = bit(6);
+            }
+            StatusKind::OfflineCause => {
+                status.cover_open = bit(2);
+                status.paper_feeding = bit(3);
+                status.paper_end_stop = bit(5);
+                status.error = bit(6);
+            }
+            StatusKind::Error => {
+                status.mechanical_error = bit(2);
+                status.cutter_error = bit(3);
+                status.unrecoverable_error = bit(5);
+                status.auto_recoverable_error = bit(6);
+            }
+            StatusKind::PaperSensor => {
+                // Each sensor is reported on two bits
+                status.paper_near_end = byte & 0x0C == 0x0C;
+                status.paper_out = byte & 0x60 == 0x60;
+            }
+        }
+        Ok(status)
+    }
+
+    /// Decodes an Automatic Status Back message into status flags. Unlike a `DLE EOT n` reply, a message carries
+    /// all the flags [`AsbStatus`] has.
+    /// ```rust
+    /// use escpos_rw::PrinterStatus;
+    /// # fn main() -> Result<(), escpos_rw::Error> {
+    /// // Drawer open, paper near its end
+    /// let status = PrinterStatus::from_asb_bytes(&[0x14, 0x00, 0x03, 0x00])?;
+    /// assert!(status.drawer_open);
+    /// assert!(status.paper_near_end);
+    /// assert!(!status.paper_out);
+    /// assert!(PrinterStatus::from_asb_bytes(&[0x12, 0x00, 0x00, 0x00]).is_err());
+    /// # Ok(())}
+    /// ```
+    pub fn from_asb_bytes(bytes: &[u8; 4]) -> Result<PrinterStatus, Error> {
+        if !AsbStatus::is_asb(bytes) {
+            return Err(Error::PrinterError(format!(
+                "Not a status message: {:02x?}",
+                bytes
+            )));
+        }
+        let asb = AsbStatus::from_bytes(bytes);
+        Ok(PrinterStatus {
+            drawer_open: asb.drawer_open,
+            offline: asb.offline,
+            cover_open: asb.cover_open,
+            feed_button_pressed: asb.feed_button_pressed,
+            mechanical_error: asb.mechanical_error,
+            cutter_error: asb.cutter_error,
+            unrecoverable_error: asb.unrecoverable_error,
+            auto_recoverable_error: asb.auto_recoverable_error,
+            paper_near_end: asb.paper_near_end,
+            paper_out: asb.paper_out,
+            ..PrinterStatus::default()
+        })
+    }
+}
+
+/// Status changes reported by Automatic Status Back, see [`Printer::enable_asb`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AsbFlags {
+    /// Drawer kick-out connector pin 3
+    pub drawer: bool,
+    /// Going online or offline
+    pub online_offline: bool,
+    /// Errors
+    pub error: bool,
+    /// Roll paper sensors
+    pub paper_sensor: bool,
+}
+
+impl AsbFlags {
+    /// All the status changes
+    pub const ALL: AsbFlags = AsbFlags {
+        drawer: true,
+        online_offline: true,
+        error: true,
+        paper_sensor: true,
+    };
+}
+
+impl Printer {
+    /// Queries the printer's real-time status
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::StatusKind;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let status = printer.status(StatusKind::Error)?;
+    /// if status.cutter_error {
+    ///     println!("Paper jam !");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn status(&self, kind: StatusKind) -> Result<PrinterStatus, Error> {
+        self.write_raw([0x10, 0x04, kind.as_byte()])?;
+        let (buffer, read) = self.read_raw_counted()?;
+        if read == 0 {
+            return Err(Error::PrinterError(
+                "The printer sent no status".to_string(),
+            ));
+        }
+        PrinterStatus::from_byte(kind, buffer[0])
+    }
+
+    /// Recovers from a recoverable error, like a cleared cutter jam, after clearing the receive and print buffers (`DLE ENQ 2`).
+    ///
+    /// Automatically recoverable errors don't need this, and unrecoverable errors can't be recovered that way.
+    /// See [`PrinterStatus`].
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::StatusKind;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// if printer.status(StatusKind::Error)?.cutter_error {
+    ///     // Once the jam has been cleared by the operator
+    ///     printer.recover_from_error()?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn recover_from_error(&self) -> Result<(), Error> {
+        self.write_raw([0x10, 0x05, 0x02])
+    }
+
+    /// Enables Automatic Status Back (`GS a n`) for the given status changes: the printer then sends a status
+    /// message whenever one of them happens, see [`Printer::poll_asb`]. With no flags set, ASB is disabled.
+    pub fn enable_asb(&self, flags: AsbFlags) -> Result<(), Error> {
+        let n = flags.drawer as u8
+            | (flags.online_offline as u8) << 1
+            | (flags.error as u8) << 2
+            | (flags.paper_sensor as u8) << 3;
+        self.write_raw([0x1D, 0x61, n])
+    }
+
+    /// Decodes a pending Automatic Status Back message, if any, without waiting for one.
+    ///
+    /// If several messages are pending, the most recent one is returned.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::AsbFlags;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.enable_asb(AsbFlags::ALL)?;
+    /// loop {
+    ///     if let Some(status) = printer.poll_asb()? {
+    ///         if status.paper_out {
+    ///             println!("Out of paper !");
+    ///         }
+    ///     }
+    ///     std::thread::sleep(std::time::Duration::from_millis(100));
+    /// }
+    /// # }
+    /// ```
+    pub fn poll_asb(&self) -> Result<Option<AsbStatus>, Error> {
+        let mut buffer = [0; 64];
+        let read = match self.read_within(&mut buffer, ASB_POLL_TIMEOUT) {
+            Ok(read) => read,
+            Err(Error::Timeout) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        if read == 0 {
+            return Ok(None);
+        }
+        if read % 4 != 0 {
+            return Err(Error::PrinterError(format!(
+                "Incomplete status message: {} bytes",
+                read
+            )));
+        }
+        let mut message = [0; 4];
+        message.copy_from_slice(&buffer[read - 4..read]);
+        if !AsbStatus::is_asb(&message) {
+            return Err(Error::PrinterError(format!(
+                "Not a status message: {:02x?}",
+                message
+            )));
+        }
+        Ok(Some(AsbStatus::from_bytes(&message)))
+    }
+
+    /// Sends bytes to the printer, then checks it didn't go offline (`DLE EOT 2`).
+    ///
+    /// This is a lighter check than decoding every status: the returned error names the offline causes.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// if let Err(e) = printer.print_and_check_offline(b"Total: 12.50\n") {
+    ///     // "Printer error: Printer is offline: cover is open"
+    ///     println!("{}", e);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn print_and_check_offline(&self, bytes: &[u8]) -> Result<(), Error> {
+        self.write_raw(bytes)?;
+        let status = self.status(StatusKind::OfflineCause)?;
+        let causes: Vec<&str> = [
+            (status.cover_open, "cover is open"),
+            (status.paper_feeding, "feed button is feeding paper"),
+            (status.paper_end_stop, "printing stopped on a paper end"),
+            (status.error, "an error occurred"),
+        ]
+        .into_iter()
+        .filter_map(|(set, cause)| set.then_some(cause))
+        .collect();
+        if causes.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::PrinterError(format!(
+                "Printer is offline: {}",
+                causes.join(", ")
+            )))
+        }
+    }
+
+    /// Checks a cut succeeded, decoding why it failed from the error (`DLE EOT 3`), paper sensor (`DLE EOT 4`) and
+    /// offline cause (`DLE EOT 2`) statuses.
+    ///
+    /// Status requests are answered as soon as they are received, even while previous commands are still waiting
+    /// in the printer's buffer: call this once the cut had time to complete.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::CutResult;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Full cut
+    /// printer.write_raw([0x1D, 0x56, 0x00])?;
+    /// std::thread::sleep(std::time::Duration::from_millis(500));
+    /// match printer.verify_cut()? {
+    ///     CutResult::Cut => {}
+    ///     CutResult::CutterError => println!("Cutter jammed !"),
+    ///     CutResult::CoverOpen => println!("Close the cover"),
+    ///     CutResult::PaperOut => println!("Out of paper !"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify_cut(&self) -> Result<CutResult, Error> {
+        let error = self.status(StatusKind::Error)?;
+        let paper = self.status(StatusKind::PaperSensor)?;
+        let offline = self.status(StatusKind::OfflineCause)?;
+        Ok(CutResult::from_statuses(&error, &paper, &offline))
+    }
+
+    /// Returns true if the printer meets the given criteria. Only the needed status requests are sent.
+    pub fn is_ready(&self, criteria: ReadyCriteria) -> Result<bool, Error> {
+        if criteria.online && self.status(StatusKind::Printer)?.offline {
+            return Ok(false);
+        }
+        if criteria.paper_present && self.status(StatusKind::PaperSensor)?.paper_out {
+            return Ok(false);
+        }
+        if criteria.cover_closed && self.status(StatusKind::OfflineCause)?.cover_open {
+            return Ok(false);
+        }
+        // Head overheating is the automatically recoverable error
+        if criteria.not_overheated && self.status(StatusKind::Error)?.auto_recoverable_error {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Polls the printer status every `poll_interval` until it meets the given criteria, or fails with
+    /// [`Error::Timeout`] after `max_wait`.
+    ///
+    /// Waiting before sending a large job avoids filling the printer buffer faster than it prints.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::ReadyCriteria;
+    /// use std::time::Duration;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.wait_until_ready(
+    ///     ReadyCriteria::default(),
+    ///     Duration::from_millis(200),
+    ///     Duration::from_secs(30),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_until_ready(
+        &self,
+        criteria: ReadyCriteria,
+        poll_interval: Duration,
+        max_wait: Duration,
+    ) -> Result<(), Error> {
+        let start = Instant::now();
+        loop {
+            if self.is_ready(criteria)? {
+                return Ok(());
+            }
+            if start.elapsed() >= max_wait {
+                return Err(Error::Timeout);
+            }
+            thread::sleep(poll_interval);
+        }
+    }
+}
+
+/// Maximum time to wait for a pending Automatic Status Back message
+const ASB_POLL_TIMEOUT: Duration = Duration::from_millis(10);
+
+/// Decoded Automatic Status Back (ASB) message.
+///
+/// Once enabled with `GS a n`, the printer spontaneously sends these 4 bytes messages when its status changes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AsbStatus {
+    /// Drawer kick-out connector pin 3 is high
+    pub drawer_open: bool,
+    /// Printer is offline
+    pub offline: bool,
+    /// Cover is open
+    pub cover_open: bool,
+    /// Paper feed button is being pressed
+    pub feed_button_pressed: bool,
+    /// Mechanical error, on models reporting it
+    pub mechanical_error: bool,
+    /// Autocutter error, usually a paper jam
+    pub cutter_error: bool,
+    /// Unrecoverable error
+    pub unrecoverable_error: bool,
+    /// Automatically recoverable error, like print head overheating
+    pub auto_recoverable_error: bool,
+    /// Roll paper is near its end
+    pub paper_near_end: bool,
+    /// Roll paper is not present
+    pub paper_out: bool,
+}
+
+impl AsbStatus {
+    /// Decodes an ASB message
+    /// ```rust
+    /// use escpos_rw::AsbStatus;
+    /// // Cover open, paper out
+    /// let status = AsbStatus::from_bytes(&[0x30, 0x00, 0x0C, 0x00]);
+    /// assert!(status.cover_open);
+    /// assert!(status.paper_out);
+    /// assert!(!status.offline);
+    /// ```
+    pub fn from_bytes(bytes: &[u8; 4]) -> AsbStatus {
+        let bit = |byte: usize, n: u8| bytes[byte] & (1 << n) != 0;
+        AsbStatus {
+            drawer_open: bit(0, 2),
+            offline: bit(0, 3),
+            cover_open: bit(0, 5),
+            feed_button_pressed: bit(0, 6),
+            mechanical_error: bit(1, 2),
+            cutter_error: bit(1, 3),
+            unrecoverable_error: bit(1, 5),
+            auto_recoverable_error: bit(1, 6),
+            // Each sensor is reported on two bits
+            paper_near_end: bytes[2] & 0x03 == 0x03,
+            paper_out: bytes[2] & 0x0C == 0x0C,
+        }
+    }
+
+    /// Returns true if the bytes have the fixed bits of an ASB message
+    pub(crate) fn is_asb(bytes: &[u8; 4]) -> bool {
+        bytes[0] & 0x93 == 0x10 && bytes[1..].iter().all(|b| b & 0x90 == 0)
+    }
+}
+
+/// Outcome of a cut, see [`Printer::verify_cut`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CutResult {
+    /// The paper was cut
+    Cut,
+    /// The autocutter reports an error, usually a paper jam. Once cleared, see [`Printer::recover_from_error`].
+    CutterError,
+    /// The cover is open
+    CoverOpen,
+    /// There is no paper to cut
+    PaperOut,
+}
+
+impl CutResult {
+    /// Decodes the error, paper sensor and offline cause statuses. A cutter error takes precedence, as it needs a
+    /// recovery once its cause is gone.
+    fn from_statuses(
+        error: &PrinterStatus,
+        paper: &PrinterStatus,
+        offline: &PrinterStatus,
+    ) -> CutResult {
+        if error.cutter_error {
+            CutResult::CutterError
+        } else if offline.cover_open {
+            CutResult::CoverOpen
+        } else if paper.paper_out {
+            CutResult::PaperOut
+        } else {
+            CutResult::Cut
+        }
+    }
+}
+
+/// Conditions the printer must meet for [`Printer::wait_until_ready`] to return.
+///
+/// The default criteria are online and paper present.
+/// ```rust
+/// use escpos_rw::ReadyCriteria;
+/// // A final receipt also wants the cover closed
+/// let criteria = ReadyCriteria {
+///     cover_closed: true,
+///     ..ReadyCriteria::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadyCriteria {
+    /// Printer is online
+    pub online: bool,
+    /// Roll paper is present
+    pub paper_present: bool,
+    /// Cover is closed
+    pub cover_closed: bool,
+    /// Print head is not overheated
+    pub not_overheated: bool,
+}
+
+impl Default for ReadyCriteria {
+    fn default() -> Self {
+        ReadyCriteria {
+            online: true,
+            paper_present: true,
+            cover_closed: false,
+            not_overheated: false,
+        }
+    }
+}
+
+/// Events reported by a [`StatusMonitor`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusEvent {
+    /// The paper feed button has been pressed
+    ButtonPressed,
+    /// The paper feed button has been released
+    ButtonReleased,
+}
+
+/// Polls the printer status and turns its changes into events. Created by [`Printer::monitor`].
+///
+/// The feed button state is reported by Epson TM printers (TM-T20, TM-T88 series...), which also feed paper when
+/// it is pressed unless the button has been disabled with `ESC c 5`. Many clones always report it as released.
+pub struct StatusMonitor<'a> {
+    printer: &'a Printer,
+    /// Button state at the last poll
+    button_pressed: bool,
+}
+
+impl StatusMonitor<'_> {
+    /// Polls the printer once, returning an event if something changed since the previous poll
+    pub fn poll(&mut self) -> Result<Option<StatusEvent>, Error> {
+        let status = self.printer.status(StatusKind::Printer)?;
+        Ok(self.update(&status))
+    }
+
+    fn update(&mut self, status: &PrinterStatus) -> Option<StatusEvent> {
+        if status.feed_button_pressed == self.button_pressed {
+            return None;
+        }
+        self.button_pressed = status.feed_button_pressed;
+        if self.button_pressed {
+            Some(StatusEvent::ButtonPressed)
+        } else {
+            Some(StatusEvent::ButtonReleased)
+        }
+    }
+}
+
+impl Printer {
+    /// Creates a [`StatusMonitor`], to react to the operator pressing the feed button
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::StatusEvent;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// let mut monitor = printer.monitor();
+    /// // Waits for the operator to acknowledge
+    /// while monitor.poll()? != Some(StatusEvent::ButtonPressed) {
+    ///     std::thread::sleep(std::time::Duration::from_millis(100));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn monitor(&self) -> StatusMonitor<'_> {
+        StatusMonitor {
+            printer: self,
+            button_pressed: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statuses(error: u8, paper: u8, offline: u8) -> CutResult {
+        CutResult::from_statuses(
+            &PrinterStatus::from_byte(StatusKind::Error, error).unwrap(),
+            &PrinterStatus::from_byte(StatusKind::PaperSensor, paper).unwrap(),
+            &PrinterStatus::from_byte(StatusKind::OfflineCause, offline).unwrap(),
+        )
+    }
+
+    #[test]
+    fn cut_result() {
+        assert_eq!(statuses(0x12, 0x12, 0x12), CutResult::Cut);
+        assert_eq!(statuses(0x1A, 0x12, 0x12), CutResult::CutterError);
+        assert_eq!(statuses(0x12, 0x12, 0x16), CutResult::CoverOpen);
+        assert_eq!(statuses(0x12, 0x72, 0x12), CutResult::PaperOut);
+        // Paper near its end still cuts
+        assert_eq!(statuses(0x12, 0x1E, 0x12), CutResult::Cut);
+        // A jam with the cover opened to clear it
+        assert_eq!(statuses(0x1A, 0x12, 0x16), CutResult::CutterError);
+    }
+
+    #[test]
+    fn printer_status() {
+        let status = PrinterStatus::from_byte(StatusKind::Printer, 0x16).unwrap();
+        assert_eq!(
+            status,
+            PrinterStatus {
+                drawer_open: true,
+                ..PrinterStatus::default()
+            }
+        );
+        let status = PrinterStatus::from_byte(StatusKind::Printer, 0x7E).unwrap();
+        assert!(status.drawer_open && status.offline);
+        assert!(status.waiting_for_recovery && status.feed_button_pressed);
+    }
+
+    #[test]
+    fn offline_cause_status() {
+        let status = PrinterStatus::from_byte(StatusKind::OfflineCause, 0x72).unwrap();
+        assert_eq!(
+            status,
+            PrinterStatus {
+                paper_end_stop: true,
+                error: true,
+                ..PrinterStatus::default()
+            }
+        );
+    }
+
+    #[test]
+    fn error_status() {
+        let status = PrinterStatus::from_byte(StatusKind::Error, 0x36).unwrap();
+        assert_eq!(
+            status,
+            PrinterStatus {
+                mechanical_error: true,
+                unrecoverable_error: true,
+                ..PrinterStatus::default()
+            }
+        );
+    }
+
+    #[test]
+    fn paper_sensor_status() {
+        let status = PrinterStatus::from_byte(StatusKind::PaperSensor, 0x1E).unwrap();
+        assert!(status.paper_near_end && !status.paper_out);
+        let status = PrinterStatus::from_byte(StatusKind::PaperSensor, 0x72).unwrap();
+        assert!(!status.paper_near_end && status.paper_out);
+        // A single bit of a sensor isn't enough
+        let status = PrinterStatus::from_byte(StatusKind::PaperSensor, 0x36).unwrap();
+        assert!(!status.paper_near_end && !status.paper_out);
+    }
+
+    #[test]
+    fn invalid_status_bytes() {
+        for byte in [0x00, 0x13, 0x92, 0x10, 0x02, 0xFF] {
+            assert!(PrinterStatus::from_byte(StatusKind::Printer, byte).is_err());
+        }
+    }
+
+    #[test]
+    fn asb_status() {
+        assert_eq!(
+            AsbStatus::from_bytes(&[0x10, 0, 0, 0]),
+            AsbStatus::default()
+        );
+        assert_eq!(
+            AsbStatus::from_bytes(&[0x4C, 0x4C, 0x0F, 0x00]),
+            AsbStatus {
+                drawer_open: true,
+                offline: true,
+                feed_button_pressed: true,
+                mechanical_error: true,
+                cutter_error: true,
+                auto_recoverable_error: true,
+                paper_near_end: true,
+                paper_out: true,
+                ..AsbStatus::default()
+            }
+        );
+        let status = AsbStatus::from_bytes(&[0x30, 0x20, 0x00, 0x00]);
+        assert!(status.cover_open && status.unrecoverable_error);
+    }
+
+    #[test]
+    fn asb_fixed_bits() {
+        assert!(AsbStatus::is_asb(&[0x10, 0x00, 0x00, 0x00]));
+        assert!(!AsbStatus::is_asb(&[0x12, 0x00, 0x00, 0x00]));
+        assert!(!AsbStatus::is_asb(&[0x00, 0x00, 0x00, 0x00]));
+        assert!(!AsbStatus::is_asb(&[0x10, 0x80, 0x00, 0x00]));
+        assert!(!AsbStatus::is_asb(&[0x10, 0x00, 0x00, 0x10]));
+        assert!(PrinterStatus::from_asb_bytes(&[0x12, 0, 0, 0]).is_err());
+        let status = PrinterStatus::from_asb_bytes(&[0x18, 0x08, 0x0C, 0x00]).unwrap();
+        assert!(status.offline && status.cutter_error && status.paper_out);
+        assert!(!status.paper_near_end);
+    }
+}
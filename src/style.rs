@@ -0,0 +1,283 @@
+use crate::{Error, Font, FontWidth, Printer};
+
+/// Underline modes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnderlineMode {
+    /// No underline
+    #[default]
+    Off,
+    /// 1-dot thick underline
+    Single,
+    /// 2-dots thick underline
+    Double,
+}
+
+/// Bytes of the emphasized (bold) command (`ESC E n`), see [`Printer::set_bold`]
+pub fn bold_bytes(on: bool) -> Vec<u8> {
+    vec![0x1B, 0x45, on as u8]
+}
+
+/// Bytes of the underline command (`ESC - n`), see [`Printer::set_underline`]
+/// ```rust
+/// use escpos_rw::{underline_bytes, UnderlineMode};
+/// assert_eq!(underline_bytes(UnderlineMode::Double), [0x1B, 0x2D, 2]);
+/// ```
+pub fn underline_bytes(mode: UnderlineMode) -> Vec<u8> {
+    let n = match mode {
+        UnderlineMode::Off => 0,
+        UnderlineMode::Single => 1,
+        UnderlineMode::Double => 2,
+    };
+    vec![0x1B, 0x2D, n]
+}
+
+impl Printer {
+    /// Turns emphasized (bold) text on or off (`ESC E n`)
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_bold(true)?;
+    /// printer.write_raw(b"TOTAL\n")?;
+    /// printer.set_bold(false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_bold(&self, on: bool) -> Result<(), Error> {
+        self.write_raw(bold_bytes(on))
+    }
+
+    /// Sets the underline mode (`ESC - n`)
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::UnderlineMode;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_underline(UnderlineMode::Single)?;
+    /// printer.write_raw(b"Items\n")?;
+    /// printer.set_underline(UnderlineMode::Off)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_underline(&self, mode: UnderlineMode) -> Result<(), Error> {
+        self.write_raw(underline_bytes(mode))
+    }
+
+    /// Turns white on black (inverse) printing on or off (`GS B n`)
+    pub fn set_inverse(&self, on: bool) -> Result<(), Error> {
+        self.write_raw([0x1D, 0x42, on as u8])
+    }
+
+    /// Turns upside-down printing on or off (`ESC { n`), for the lines started afterwards.
+    ///
+    /// Lines are rotated by 180 degrees, which reads right when the receipt is handed over facing the customer.
+    pub fn set_upside_down(&self, on: bool) -> Result<(), Error> {
+        self.write_raw([0x1B, 0x7B, on as u8])
+    }
+
+    /// Turns 90 degrees clockwise rotation of characters on or off (`ESC V n`).
+    ///
+    /// The following characters are rotated until it is turned off (or the printer initialized), for labels printed
+    /// across the paper.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_rotation(true)?;
+    /// printer.write_raw(b"FRAGILE\n")?;
+    /// printer.set_rotation(false)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_rotation(&self, on: bool) -> Result<(), Error> {
+        self.write_raw([0x1B, 0x56, on as u8])
+    }
+
+    /// Sets the character magnification, from 1 to 8 times horizontally and vertically (`GS !`)
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// printer.set_char_size(2, 3)?;
+    /// printer.write_raw(b"MY SHOP\n")?;
+    /// printer.set_char_size(1, 1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_char_size(&self, width: u8, height: u8) -> Result<(), Error> {
+        let width = FontWidth::new(width)?.multiplier();
+        if !(1..=8).contains(&height) {
+            return Err(Error::PrinterError(format!(
+                "Character height must be between 1 and 8, got {}",
+                height
+            )));
+        }
+        let size = ((width - 1) << 4) | (height - 1);
+        self.write_raw([0x1D, 0x21, size])?;
+        self.char_size.set(size);
+        Ok(())
+    }
+
+    /// Selects the font (`ESC M n`). Layout methods, like [`Printer::section`], use the columns of the selected font.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// use escpos_rw::Font;
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // Narrower characters, to fit more columns
+    /// printer.set_font(Font::FontB)?;
+    /// printer.section("ITEMS")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_font(&self, font: Font) -> Result<(), Error> {
+        let n = match font {
+            Font::FontA => 0,
+            Font::FontB => 1,
+        };
+        self.write_raw([0x1B, 0x4D, n])?;
+        self.font.set(font);
+        Ok(())
+    }
+
+    /// Currently selected font
+    pub fn font(&self) -> Font {
+        self.font.get()
+    }
+
+    /// Sets the line spacing to `dots` vertical motion units, a dot on most printers (`ESC 3 n`).
+    ///
+    /// With 0, lines are printed over each other. [`Printer::reset_line_spacing`] goes back to the default.
+    /// ```rust,no_run
+    /// # use escpos_rw::{Error, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// # let Some(printer) = Printer::new(0x04b8, 0x0202)? else {
+    /// # return Err(escpos_rw::Error::PrinterError(
+    /// #     "No printer found !".to_string(),
+    /// # ));
+    /// # };
+    /// // A dense item list
+    /// printer.set_line_spacing(24)?;
+    /// printer.write_raw(b"Coffee 2.50\nTea 2.00\n")?;
+    /// printer.reset_line_spacing()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_line_spacing(&self, dots: u8) -> Result<(), Error> {
+        self.write_raw([0x1B, 0x33, dots])
+    }
+
+    /// Goes back to the default line spacing, about 1/6 inch (`ESC 2`)
+    pub fn reset_line_spacing(&self) -> Result<(), Error> {
+        self.write_raw([0x1B, 0x32])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VecTransport;
+
+    #[test]
+    fn style_bytes() {
+        assert_eq!(bold_bytes(true), [0x1B, 0x45, 1]);
+        assert_eq!(bold_bytes(false), [0x1B, 0x45, 0]);
+        assert_eq!(underline_bytes(UnderlineMode::Off), [0x1B, 0x2D, 0]);
+        assert_eq!(underline_bytes(UnderlineMode::Double), [0x1B, 0x2D, 2]);
+    }
+
+    fn printer() -> (Printer, VecTransport) {
+        let sink = VecTransport::new();
+        (Printer::with_transport(sink.clone()), sink)
+    }
+
+    #[test]
+    fn text_styles() {
+        let (printer, sink) = printer();
+        printer.set_bold(true).unwrap();
+        printer.set_underline(UnderlineMode::Single).unwrap();
+        printer.set_inverse(true).unwrap();
+        printer.set_bold(false).unwrap();
+        assert_eq!(
+            sink.take(),
+            [0x1B, 0x45, 1, 0x1B, 0x2D, 1, 0x1D, 0x42, 1, 0x1B, 0x45, 0]
+        );
+    }
+
+    #[test]
+    fn char_size() {
+        let (printer, sink) = printer();
+        printer.set_char_size(1, 1).unwrap();
+        printer.set_char_size(2, 3).unwrap();
+        printer.set_char_size(8, 8).unwrap();
+        assert_eq!(
+            sink.take(),
+            [0x1D, 0x21, 0x00, 0x1D, 0x21, 0x12, 0x1D, 0x21, 0x77]
+        );
+    }
+
+    #[test]
+    fn char_size_out_of_range() {
+        let (printer, sink) = printer();
+        assert!(printer.set_char_size(0, 1).is_err());
+        assert!(printer.set_char_size(9, 1).is_err());
+        assert!(printer.set_char_size(1, 0).is_err());
+        assert!(printer.set_char_size(1, 9).is_err());
+        assert!(sink.bytes().is_empty());
+    }
+
+    #[test]
+    fn font() {
+        let (printer, sink) = printer();
+        printer.set_font(Font::FontB).unwrap();
+        assert_eq!(printer.font(), Font::FontB);
+        printer.set_font(Font::FontA).unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x4D, 1, 0x1B, 0x4D, 0]);
+    }
+
+    #[test]
+    fn line_spacing() {
+        let (printer, sink) = printer();
+        printer.set_line_spacing(24).unwrap();
+        printer.reset_line_spacing().unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x33, 24, 0x1B, 0x32]);
+    }
+
+    #[test]
+    fn rotation_and_upside_down() {
+        let (printer, sink) = printer();
+        printer.set_upside_down(true).unwrap();
+        printer.set_rotation(true).unwrap();
+        printer.set_upside_down(false).unwrap();
+        assert_eq!(sink.take(), [0x1B, 0x7B, 1, 0x1B, 0x56, 1, 0x1B, 0x7B, 0]);
+    }
+
+    #[test]
+    fn char_size_is_tracked() {
+        let (printer, _sink) = printer();
+        printer.set_char_size(8, 8).unwrap();
+        assert_eq!(printer.char_size.get(), 0x77);
+        assert!(printer.set_char_size(9, 1).is_err());
+        assert_eq!(printer.char_size.get(), 0x77);
+    }
+}
@@ -0,0 +1,385 @@
+use crate::{Error, Font, Justification, Printer, PrinterProfile, Transport};
+use std::{
+    io::Write,
+    sync::{Mutex, MutexGuard},
+};
+
+/// ANSI escape sequences
+const ANSI_BOLD: &str = "\x1b[1m";
+const ANSI_UNDERLINE: &str = "\x1b[4m";
+const ANSI_INVERSE: &str = "\x1b[7m";
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// State of the terminal "printer"
+struct Terminal {
+    /// Characters a line holds
+    columns: usize,
+    /// Start of a command, waiting for the next write to be complete
+    pending: Vec<u8>,
+    /// Line being printed, with its ANSI escape sequences
+    line: String,
+    /// Number of characters in the line
+    line_width: usize,
+    bold: bool,
+    underline: bool,
+    inverse: bool,
+    justification: Justification,
+}
+
+impl Terminal {
+    fn new(columns: usize) -> Terminal {
+        Terminal {
+            columns: columns.max(1),
+            pending: Vec::new(),
+            line: String::new(),
+            line_width: 0,
+            bold: false,
+            underline: false,
+            inverse: false,
+            justification: Justification::Left,
+        }
+    }
+
+    /// Decodes the bytes, returning what is to be shown
+    fn render(&mut self, bytes: &[u8]) -> String {
+        let mut bytes = std::mem::take(&mut self.pending)
+            .into_iter()
+            .chain(bytes.iter().copied())
+            .collect::<Vec<u8>>();
+        let mut output = String::new();
+        let mut position = 0;
+        while position < bytes.len() {
+            let remaining = &bytes[position..];
+            let Some(length) = command_length(remaining) else {
+                // Incomplete command, decoded with the next bytes
+                self.pending = bytes.split_off(position);
+                break;
+            };
+            self.execute(&remaining[..length], &mut output);
+            position += length;
+        }
+        output
+    }
+
+    /// Executes a single command or character
+    fn execute(&mut self, command: &[u8], output: &mut String) {
+        match command {
+            [0x0A] => self.print_line(output),
+            [0x09] => self.push(' ', output),
+            [0x1B, 0x40] => *self = Terminal::new(self.columns),
+            [0x1B, 0x45, n] => self.set_style(|t| &mut t.bold, n & 1 == 1),
+            [0x1B, 0x2D, n] => self.set_style(|t| &mut t.underline, n & 3 != 0),
+            [0x1B, 0x21, n] => {
+                self.set_style(|t| &mut t.bold, n & 0x08 != 0);
+                self.set_style(|t| &mut t.underline, n & 0x80 != 0);
+            }
+            [0x1D, 0x42, n] => self.set_style(|t| &mut t.inverse, n & 1 == 1),
+            [0x1B, 0x61, n] => {
+                self.justification = match n {
+                    1 | b'1' => Justification::Center,
+                    2 | b'2' => Justification::Right,
+                    _ => Justification::Left,
+                }
+            }
+            [0x1B, 0x64, n] => {
+                let mut lines = *n as usize;
+                if self.line_width > 0 {
+                    self.print_line(output);
+                    lines = lines.saturating_sub(1);
+                }
+                output.push_str(&"\n".repeat(lines));
+            }
+            [0x1D, 0x56, ..] => {
+                if self.line_width > 0 {
+                    self.print_line(output);
+                }
+                output.push_str(&"- ".repeat(self.columns / 2));
+                output.push('\n');
+            }
+            [0x1D, 0x6B, ..] => self.print_placeholder("[barcode]", output),
+            // Print the QR code stored with GS ( k
+            [0x1D, 0x28, 0x6B, 0x03, 0x00, 0x31, 0x51, 0x30] => {
+                self.print_placeholder("[QR code]", output)
+            }
+            [0x1D, 0x76, 0x30, _, x_l, x_h, y_l, y_h, ..] => {
+                let width = u16::from_le_bytes([*x_l, *x_h]) as usize * 8;
+                let height = u16::from_le_bytes([*y_l, *y_h]);
+                self.print_placeholder(&format!("[image {}x{}]", width, height), output)
+            }
+            [b] if *b >= 0x20 && *b != 0x7F => self.push(char::from(*b), output),
+            // Other commands don't change what is shown
+            _ => {}
+        }
+    }
+
+    /// Turns a style on or off, in the line being printed too
+    fn set_style(&mut self, style: fn(&mut Terminal) -> &mut bool, on: bool) {
+        *style(self) = on;
+        if self.line_width > 0 {
+            self.line.push_str(ANSI_RESET);
+            self.line.push_str(&self.style_sequences());
+        }
+    }
+
+    /// ANSI escape sequences of the current styles
+    fn style_sequences(&self) -> String {
+        let mut sequences = String::new();
+        if self.bold {
+            sequences.push_str(ANSI_BOLD);
+        }
+        if self.underline {
+            sequences.push_str(ANSI_UNDERLINE);
+        }
+        if self.inverse {
+            sequences.push_str(ANSI_INVERSE);
+        }
+        sequences
+    }
+
+    /// Adds a character to the line, printing it first if it is full
+    fn push(&mut self, c: char, output: &mut String) {
+        if self.line_width == self.columns {
+            self.print_line(output);
+        }
+        if self.line_width == 0 {
+            self.line.push_str(&self.style_sequences());
+        }
+        self.line.push(c);
+        self.line_width += 1;
+    }
+
+    /// Prints the line, justified, and starts a new one
+    fn print_line(&mut self, output: &mut String) {
+        let free = self.columns.saturating_sub(self.line_width);
+        let padding = match self.justification {
+            Justification::Left => 0,
+            Justification::Center => free / 2,
+            Justification::Right => free,
+        };
+        output.push_str(&" ".repeat(padding));
+        output.push_str(&self.line);
+        if self.line.contains('\x1b') {
+            output.push_str(ANSI_RESET);
+        }
+        output.push('\n');
+        self.line.clear();
+        self.line_width = 0;
+    }
+
+    /// Prints a line standing for what can't be shown, like images
+    fn print_placeholder(&mut self, placeholder: &str, output: &mut String) {
+        if self.line_width > 0 {
+            self.print_line(output);
+        }
+        placeholder.chars().for_each(|c| self.push(c, output));
+        self.print_line(output);
+    }
+}
+
+/// Length of the command or character starting `bytes`, `None` if it is incomplete
+fn command_length(bytes: &[u8]) -> Option<usize> {
+    let byte = |index: usize| bytes.get(index).copied();
+    let word = |index: usize| Some(u16::from_le_bytes([byte(index)?, byte(index + 1)?]) as usize);
+    let length = match byte(0)? {
+        // ESC
+        0x1B => match byte(1)? {
+            b'@' | b'2' | b'<' | b'i' | b'm' => 2,
+            b'$' | b'\\' => 4,
+            b'c' => 4,
+            b'p' => 5,
+            b'W' => 10,
+            // Tab stops, NUL terminated
+            b'D' => bytes.iter().skip(2).position(|b| *b == 0)? + 3,
+            // Bit image
+            b'*' => {
+                let dots = if byte(2)? < 32 { 1 } else { 3 };
+                5 + word(3)? * dots
+            }
+            // User-defined characters
+            b'&' => {
+                let height = byte(2)? as usize;
+                let (first, last) = (byte(3)?, byte(4)?);
+                let mut length = 5;
+                for _ in first..=last {
+                    length += 1 + height * byte(length)? as usize;
+                }
+                length
+            }
+            _ => 3,
+        },
+        // GS
+        0x1D => match byte(1)? {
+            b'V' => match byte(2)? {
+                65 | 66 | 97 | 98 | 103 | 104 => 4,
+                _ => 3,
+            },
+            b'$' | b'\\' | b'L' | b'W' | b'P' => 4,
+            b'^' => 5,
+            // Barcode: NUL terminated, or length-prefixed
+            b'k' => match byte(2)? {
+                0..=6 => bytes.iter().skip(3).position(|b| *b == 0)? + 4,
+                _ => 4 + byte(3)? as usize,
+            },
+            b'(' => 5 + word(3)?,
+            // Raster image
+            b'v' => 8 + word(4)? * word(6)?,
+            // Downloaded bit image
+            b'*' => 4 + byte(2)? as usize * byte(3)? as usize * 8,
+            b'8' => {
+                let p = u32::from_le_bytes([byte(3)?, byte(4)?, byte(5)?, byte(6)?]);
+                7 + p as usize
+            }
+            _ => 3,
+        },
+        // DLE
+        0x10 => match byte(1)? {
+            0x14 => 5,
+            _ => 3,
+        },
+        // FS
+        0x1C => match byte(1)? {
+            b'p' => 4,
+            _ => 2,
+        },
+        _ => 1,
+    };
+    (bytes.len() >= length).then_some(length)
+}
+
+/// Shows what is sent to the printer in the terminal, to preview receipts without a printer.
+///
+/// Text is shown with its justification, bold, underlined and inverse text with ANSI escape sequences, cuts as
+/// dashed lines. Barcodes, QR codes and images are replaced with placeholders, and other commands ignored.
+/// Characters past 0x7F are shown as their Latin-1 equivalent.
+pub struct TerminalTransport {
+    terminal: Mutex<Terminal>,
+}
+
+impl TerminalTransport {
+    /// Creates a terminal transport, with lines of `columns` characters
+    pub fn new(columns: u8) -> TerminalTransport {
+        TerminalTransport {
+            terminal: Mutex::new(Terminal::new(columns as usize)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Terminal> {
+        self.terminal
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Transport for TerminalTransport {
+    fn write(&self, bytes: &[u8]) -> Result<(), Error> {
+        let output = self.lock().render(bytes);
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(output.as_bytes())?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+impl Printer {
+    /// Creates a printer showing what it prints in the terminal, with lines as long as the profile's font A ones,
+    /// see [`TerminalTransport`]
+    /// ```rust
+    /// use escpos_rw::{Error, Justification, Printer, PrinterProfile};
+    /// # fn main() -> Result<(), Error> {
+    /// let printer = Printer::new_terminal(PrinterProfile::builder().width(384).build());
+    /// printer.set_justification(Justification::Center)?;
+    /// printer.set_bold(true)?;
+    /// printer.write_raw(b"MY SHOP\n")?;
+    /// printer.finish()?;
+    /// # Ok(())}
+    /// ```
+    pub fn new_terminal(profile: PrinterProfile) -> Printer {
+        let mut printer =
+            Printer::with_transport(TerminalTransport::new(profile.columns(Font::FontA)));
+        printer.set_profile(profile);
+        printer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_lengths() {
+        assert_eq!(command_length(b"A"), Some(1));
+        assert_eq!(command_length(b"\n"), Some(1));
+        assert_eq!(command_length(b"\x1bE\x01AB"), Some(3));
+        assert_eq!(command_length(b"\x1b@"), Some(2));
+        assert_eq!(command_length(b"\x1bp\x00\x32\x32"), Some(5));
+        assert_eq!(command_length(b"\x1bD\x08\x10\x00A"), Some(5));
+        assert_eq!(command_length(b"\x1dV\x41\x03"), Some(4));
+        assert_eq!(command_length(b"\x1dkE\x03ABC"), Some(7));
+        assert_eq!(command_length(b"\x1dk\x04ABC\x00"), Some(7));
+        assert_eq!(command_length(b"\x1d(k\x03\x001C\x04"), Some(8));
+        // 1 byte wide, 2 rows high raster image
+        assert_eq!(
+            command_length(b"\x1dv0\x00\x01\x00\x02\x00\xff\x00"),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn unknown_sequences() {
+        // Assumed to have one parameter
+        assert_eq!(command_length(b"\x1bz\x05"), Some(3));
+        assert_eq!(command_length(b"\x1dz\x05"), Some(3));
+        assert_eq!(command_length(b"\x10z\x05"), Some(3));
+        assert_eq!(command_length(b"\x1cz"), Some(2));
+    }
+
+    #[test]
+    fn partial_commands() {
+        assert_eq!(command_length(b""), None);
+        assert_eq!(command_length(b"\x1b"), None);
+        assert_eq!(command_length(b"\x1bE"), None);
+        assert_eq!(command_length(b"\x1bD\x08\x10"), None);
+        assert_eq!(command_length(b"\x1dk\x04ABC"), None);
+        assert_eq!(command_length(b"\x1dkE\x03AB"), None);
+        assert_eq!(command_length(b"\x1d(k\x03"), None);
+        assert_eq!(command_length(b"\x1dv0\x00\x01\x00\x02\x00\xff"), None);
+    }
+
+    #[test]
+    fn render_text() {
+        let mut terminal = Terminal::new(8);
+        assert_eq!(terminal.render(b"AB\nCD"), "AB\n");
+        assert_eq!(terminal.render(b"\x1ba\x02\n"), "      CD\n");
+        // Lines longer than the columns wrap
+        assert_eq!(terminal.render(b"\x1ba\x00ABCDEFGHIJ\n"), "ABCDEFGH\nIJ\n");
+    }
+
+    #[test]
+    fn render_commands_split_across_writes() {
+        let mut terminal = Terminal::new(8);
+        assert_eq!(terminal.render(b"\x1b"), "");
+        assert_eq!(terminal.render(b"E"), "");
+        assert_eq!(terminal.render(b"\x01AB\n"), "\x1b[1mAB\x1b[0m\n");
+        // Image data split in the middle
+        let mut terminal = Terminal::new(16);
+        assert_eq!(terminal.render(b"\x1dv0\x00\x01\x00\x02\x00\xff"), "");
+        assert_eq!(terminal.render(b"\x00"), "[image 8x2]\n");
+    }
+
+    #[test]
+    fn render_ignores_unknown_sequences() {
+        let mut terminal = Terminal::new(8);
+        assert_eq!(terminal.render(b"\x1bz\x05X\x1dz\x05Y\n"), "XY\n");
+        // Control characters aren't shown
+        assert_eq!(terminal.render(b"A\x00\x7fB\n"), "AB\n");
+    }
+
+    #[test]
+    fn render_keeps_trailing_partial_command() {
+        let mut terminal = Terminal::new(8);
+        assert_eq!(terminal.render(b"AB\x1d"), "");
+        assert_eq!(terminal.pending, b"\x1d");
+        assert_eq!(terminal.render(b"B\x01C\n"), "AB\x1b[0m\x1b[7mC\x1b[0m\n");
+        assert!(terminal.pending.is_empty());
+    }
+}
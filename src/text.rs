@@ -0,0 +1,262 @@
+use crate::{CodePage, Error};
+use unicode_width::UnicodeWidthChar;
+
+/// Horizontal character magnification, from 1 (normal) to 8
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontWidth(u8);
+
+impl FontWidth {
+    /// Normal width characters
+    pub const NORMAL: FontWidth = FontWidth(1);
+    /// Double width characters
+    pub const DOUBLE: FontWidth = FontWidth(2);
+
+    /// Creates a magnification factor, which must be in 1..=8
+    pub fn new(multiplier: u8) -> Result<FontWidth, Error> {
+        if (1..=8).contains(&multiplier) {
+            Ok(FontWidth(multiplier))
+        } else {
+            Err(Error::PrinterError(format!(
+                "Character width must be between 1 and 8, got {}",
+                multiplier
+            )))
+        }
+    }
+
+    /// Magnification factor
+    pub fn multiplier(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for FontWidth {
+    fn default() -> Self {
+        FontWidth::NORMAL
+    }
+}
+
+/// Number of columns a string occupies once printed.
+///
+/// Wide glyphs (CJK) take two columns, combining marks and control characters none, and everything is scaled
+/// by the character width magnification.
+///
+/// This measures the text itself, as printed in a double-byte (Kanji) mode. The layout helpers such as
+/// [`Printer::print_row`](crate::Printer::print_row) measure the text encoded with the active code page instead,
+/// where each byte takes one column, `?` included.
+/// ```rust
+/// use escpos_rw::{display_columns, FontWidth};
+/// assert_eq!(display_columns("Total", FontWidth::NORMAL), 5);
+/// assert_eq!(display_columns("Total", FontWidth::DOUBLE), 10);
+/// // CJK characters are double width
+/// assert_eq!(display_columns("日本語", FontWidth::NORMAL), 6);
+/// // "é" written as "e" followed by a combining acute accent
+/// assert_eq!(display_columns("cafe\u{301}", FontWidth::NORMAL), 4);
+/// assert_eq!(display_columns("A\tB\n", FontWidth::NORMAL), 2);
+/// ```
+pub fn display_columns(text: &str, font_width: FontWidth) -> usize {
+    let columns: usize = text.chars().map(|c| c.width().unwrap_or(0)).sum();
+    columns * font_width.multiplier() as usize
+}
+
+/// Printer fonts
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Font {
+    /// Font A, 12x24 dots
+    #[default]
+    FontA,
+    /// Font B, 9x17 dots
+    FontB,
+}
+
+impl Font {
+    /// Width of a character, in dots
+    pub(crate) fn dots_width(self) -> u16 {
+        match self {
+            Font::FontA => 12,
+            Font::FontB => 9,
+        }
+    }
+}
+
+/// Word-wraps text, encoded with `codepage`, to lines of at most `columns` characters. Words too long for a line are
+/// split.
+///
+/// Each encoded byte is printed as one character: the layout is made on the encoded text, so that characters the
+/// code page doesn't have, replaced with `?`, take the column they are printed on.
+pub(crate) fn wrap(text: &str, columns: usize, codepage: CodePage) -> Vec<Vec<u8>> {
+    let columns = columns.max(1);
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let encoded = codepage.encode(paragraph, b'?');
+        let mut line = Vec::new();
+        for word in encoded
+            .split(u8::is_ascii_whitespace)
+            .filter(|word| !word.is_empty())
+        {
+            let mut word = word;
+            while word.len() > columns {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                let (prefix, rest) = word.split_at(columns);
+                lines.push(prefix.to_vec());
+                word = rest;
+            }
+            if word.is_empty() {
+                continue;
+            }
+            if line.is_empty() {
+                line.extend_from_slice(word);
+            } else if line.len() + 1 + word.len() <= columns {
+                line.push(b' ');
+                line.extend_from_slice(word);
+            } else {
+                lines.push(std::mem::replace(&mut line, word.to_vec()));
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Lays out `left` and `right`, encoded with `codepage`, on a line of `columns` characters, `right` ending at the
+/// last column. If both don't fit with a space between them, `left` is truncated with an ellipsis.
+pub(crate) fn row(left: &str, right: &str, columns: usize, codepage: CodePage) -> Vec<u8> {
+    let mut right = codepage.encode(right, b'?');
+    right.truncate(columns);
+    let available = columns - right.len();
+    let mut left = codepage.encode(left, b'?');
+    if left.len() < available || (right.is_empty() && left.len() <= available) {
+        // Fits as is
+    } else if available > 4 {
+        // Leaves room for the ellipsis and a space
+        left.truncate(available - 4);
+        left.extend_from_slice(b"...");
+    } else {
+        left.clear();
+    }
+    let padding = available - left.len();
+    left.extend(std::iter::repeat_n(b' ', padding));
+    left.extend(right);
+    left
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrap_437(text: &str, columns: usize) -> Vec<Vec<u8>> {
+        wrap(text, columns, CodePage::PC437)
+    }
+
+    #[test]
+    fn wrap_words() {
+        assert_eq!(
+            wrap_437("Organic fair trade coffee", 11),
+            [&b"Organic"[..], b"fair trade", b"coffee"]
+        );
+        // A word exactly as long as the line
+        assert_eq!(wrap_437("abcde fg", 5), [&b"abcde"[..], b"fg"]);
+    }
+
+    #[test]
+    fn wrap_splits_words_longer_than_a_line() {
+        assert_eq!(
+            wrap_437("a abcdefghij b", 4),
+            [&b"a"[..], b"abcd", b"efgh", b"ij b"]
+        );
+    }
+
+    #[test]
+    fn wrap_empty_text() {
+        assert!(wrap_437("", 10).is_empty());
+        // Empty paragraphs are kept as empty lines
+        assert_eq!(wrap_437("a\n\nb", 10), [&b"a"[..], b"", b"b"]);
+    }
+
+    #[test]
+    fn wrap_collapses_runs_of_spaces() {
+        assert_eq!(wrap_437("  a    b\t c  ", 10), [b"a b c"]);
+        assert_eq!(wrap_437("    ", 10), [b""]);
+    }
+
+    #[test]
+    fn wrap_wide_characters_take_one_column() {
+        // Printed as `?`, one column each
+        assert_eq!(wrap_437("日本語 abc", 4), [&b"???"[..], b"abc"]);
+        assert_eq!(
+            wrap(" café crème", 6, CodePage::PC850),
+            [&b"caf\x82"[..], b"cr\x8ame"]
+        );
+    }
+
+    #[test]
+    fn wrap_zero_columns() {
+        // At least one character per line
+        assert_eq!(wrap_437("ab c", 0), [&b"a"[..], b"b", b"c"]);
+    }
+
+    #[test]
+    fn display_columns_ascii() {
+        assert_eq!(display_columns("", FontWidth::NORMAL), 0);
+        assert_eq!(display_columns("Coffee 2.50", FontWidth::NORMAL), 11);
+        assert_eq!(
+            display_columns("Coffee 2.50", FontWidth::new(3).unwrap()),
+            33
+        );
+        // Control characters take no room
+        assert_eq!(display_columns("a\r\n\x1b", FontWidth::NORMAL), 1);
+    }
+
+    #[test]
+    fn display_columns_cjk() {
+        assert_eq!(display_columns("日本語", FontWidth::NORMAL), 6);
+        assert_eq!(display_columns("ラーメン 800円", FontWidth::NORMAL), 14);
+        // Full width latin letters
+        assert_eq!(display_columns("ＡＢ", FontWidth::DOUBLE), 8);
+    }
+
+    #[test]
+    fn display_columns_combining_marks() {
+        // Precomposed and decomposed forms take the same room
+        assert_eq!(display_columns("crème", FontWidth::NORMAL), 5);
+        assert_eq!(display_columns("cre\u{300}me", FontWidth::NORMAL), 5);
+        assert_eq!(display_columns("e\u{301}\u{323}", FontWidth::DOUBLE), 2);
+        // Combining mark on a wide character
+        assert_eq!(display_columns("か\u{3099}", FontWidth::NORMAL), 2);
+    }
+
+    #[test]
+    fn row_pads_between_left_and_right() {
+        assert_eq!(row("Coffee", "2.50", 12, CodePage::PC437), b"Coffee  2.50");
+        assert_eq!(row("", "2.50", 6, CodePage::PC437), b"  2.50");
+        assert_eq!(row("Coffee", "", 8, CodePage::PC437), b"Coffee  ");
+        // Left fills the line when there is no right
+        assert_eq!(row("Coffee", "", 6, CodePage::PC437), b"Coffee");
+    }
+
+    #[test]
+    fn row_truncates_left_with_an_ellipsis() {
+        assert_eq!(
+            row("Organic coffee", "2.50", 14, CodePage::PC437),
+            b"Organi... 2.50"
+        );
+        // No room for an ellipsis
+        assert_eq!(row("Coffee", "2.50", 7, CodePage::PC437), b"   2.50");
+        // Right truncated to the line
+        assert_eq!(row("Coffee", "123456", 4, CodePage::PC437), b"1234");
+    }
+
+    #[test]
+    fn row_wide_characters_take_one_column() {
+        assert_eq!(row("日本", "1.00", 8, CodePage::PC437), b"??  1.00");
+        assert_eq!(row("Crème", "€", 8, CodePage::PC858), b"Cr\x8ame  \xd5");
+    }
+
+    #[test]
+    fn row_zero_columns() {
+        assert!(row("Coffee", "2.50", 0, CodePage::PC437).is_empty());
+        assert!(row("", "", 0, CodePage::PC437).is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{Error, Printer, PrinterProfile};
+use std::{
+    fs::File,
+    io::Write,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// Where the bytes sent to a printer go, for printers which aren't USB ones, see [`Printer::with_transport`].
+///
+/// [`Printer::with_transport`]: crate::Printer::with_transport
+pub trait Transport: Send {
+    /// Sends bytes to the printer
+    fn write(&self, bytes: &[u8]) -> Result<(), Error>;
+
+    /// Reads bytes from the printer into `buffer`, returning how many were read.
+    ///
+    /// By default, fails: the transport can only be written to.
+    fn read(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        let _ = buffer;
+        Err(Error::PrinterError(
+            "The transport can't read from the printer".to_string(),
+        ))
+    }
+}
+
+/// Writes the bytes to a file, to capture what would be sent to the printer, or to send it to a device file like
+/// `/dev/usb/lp0`
+pub struct FileTransport {
+    file: File,
+}
+
+impl FileTransport {
+    /// Creates the file, truncating it if it exists
+    /// ```rust,no_run
+    /// use escpos_rw::{Error, FileTransport, Printer};
+    /// # fn main() -> Result<(), Error> {
+    /// let printer = Printer::with_transport(FileTransport::create("receipt.bin")?);
+    /// printer.write_raw(b"Hello\n")?;
+    /// # Ok(())}
+    /// ```
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<FileTransport, Error> {
+        Ok(FileTransport {
+            file: File::create(path)?,
+        })
+    }
+
+    /// Writes to an already opened file
+    pub fn new(file: File) -> FileTransport {
+        FileTransport { file }
+    }
+}
+
+impl Transport for FileTransport {
+    fn write(&self, bytes: &[u8]) -> Result<(), Error> {
+        (&self.file).write_all(bytes)?;
+        Ok(())
+    }
+}
+
+/// Collects the bytes in memory, to inspect them in tests or send them later.
+///
+/// Clones share the same bytes: keep one to read what the printer it was given wrote.
+/// ```rust
+/// use escpos_rw::{CutMode, Error, Printer, VecTransport};
+/// # fn main() -> Result<(), Error> {
+/// let sink = VecTransport::new();
+/// let printer = Printer::with_transport(sink.clone());
+/// printer.cut(CutMode::Full)?;
+/// assert_eq!(sink.bytes(), [0x1D, 0x56, 0]);
+/// # Ok(())}
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct VecTransport {
+    bytes: Arc<Mutex<Vec<u8>>>,
+}
+
+impl VecTransport {
+    /// Creates an empty transport
+    pub fn new() -> VecTransport {
+        VecTransport::default()
+    }
+
+    /// The bytes written so far
+    pub fn bytes(&self) -> Vec<u8> {
+        self.lock().clone()
+    }
+
+    /// Takes the bytes written so far, leaving the transport empty
+    pub fn take(&self) -> Vec<u8> {
+        std::mem::take(&mut self.lock())
+    }
+
+    /// Locks the bytes, even if a thread panicked while holding them: appending bytes can't leave them inconsistent
+    fn lock(&self) -> MutexGuard<'_, Vec<u8>> {
+        self.bytes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Transport for VecTransport {
+    fn write(&self, bytes: &[u8]) -> Result<(), Error> {
+        self.lock().extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// Formats bytes as a hex dump, 16 per line, with their offset and their ASCII characters, to inspect a command stream
+/// or share it in a bug report
+/// ```rust
+/// use escpos_rw::debug_hexdump;
+/// assert_eq!(
+///     debug_hexdump(b"\x1b@Hello\n"),
+///     "00000000  1b 40 48 65 6c 6c 6f 0a                          |.@Hello.|\n"
+/// );
+/// ```
+pub fn debug_hexdump(bytes: &[u8]) -> String {
+    let mut dump = String::new();
+    for (line, chunk) in bytes.chunks(16).enumerate() {
+        let hex = chunk
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let ascii = chunk
+            .iter()
+            .map(|b| match b {
+                0x20..=0x7E => char::from(*b),
+                _ => '.',
+            })
+            .collect::<String>();
+        dump.push_str(&format!("{:08x}  {:<47}  |{}|\n", line * 16, hex, ascii));
+    }
+    dump
+}
+
+impl Printer {
+    /// Creates a printer collecting the bytes it would send instead of writing them, with the given profile, and the
+    /// transport to inspect them, see [`VecTransport`] and [`debug_hexdump`]
+    /// ```rust
+    /// use escpos_rw::{debug_hexdump, Error, Printer, PrinterProfile};
+    /// # fn main() -> Result<(), Error> {
+    /// let (printer, sink) = Printer::dry_run(PrinterProfile::default());
+    /// printer.initialize()?;
+    /// printer.write_raw(b"Hello\n")?;
+    /// print!("{}", debug_hexdump(&sink.bytes()));
+    /// # assert_eq!(sink.bytes(), b"\x1b@Hello\n");
+    /// # Ok(())}
+    /// ```
+    pub fn dry_run(profile: PrinterProfile) -> (Printer, VecTransport) {
+        let sink = VecTransport::new();
+        let mut printer = Printer::with_transport(sink.clone());
+        printer.set_profile(profile);
+        (printer, sink)
+    }
+}